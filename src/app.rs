@@ -1,7 +1,4 @@
-use std::{
-    thread::{self},
-    time::Duration,
-};
+use std::time::{Duration, Instant};
 
 use ratatui::{
     crossterm::event::{self, Event, KeyCode, KeyEventKind},
@@ -9,14 +6,22 @@ use ratatui::{
 };
 
 use crate::{
-    dungeon::Dungeon,
+    campaign::Campaign,
+    class::CharacterClass,
+    combat_log::CombatLog,
     entity::Player,
+    game::GameState,
     screen::Screen,
-    utils::{ChangeWeaponOption, CombatOption, MainMenuOption},
-    zones::zone::Zone,
+    utils::{
+        render_notice, ChangeWeaponOption, ClassOption, CombatOption, ItemOption, MainMenuOption,
+        MapOption, ShopOption, SkillOption, TargetOption, ZoneOption,
+    },
+    zones::zone::{Zone, ZoneDifficulty},
 };
 use color_eyre::Result;
 
+const TICK_RATE: Duration = Duration::from_millis(50);
+
 #[allow(missing_debug_implementations)]
 #[derive(Default)]
 pub struct App {
@@ -24,49 +29,79 @@ pub struct App {
     pub current_main_menu_option: MainMenuOption,
     pub current_combat_option: CombatOption,
     pub current_change_weapon_option: ChangeWeaponOption,
-
-    pub player: Player,
-    pub dungeon: Dungeon,
+    pub current_zone_option: ZoneOption,
+    pub current_class_option: ClassOption,
+    pub current_item_option: ItemOption,
+    pub current_skill_option: SkillOption,
+    pub current_shop_option: ShopOption,
+    pub current_map_option: MapOption,
+    pub current_target_option: TargetOption,
+    pub current_zone_difficulty: ZoneDifficulty,
+    pub screen_elapsed: Duration,
+    /// Name typed in at character creation.
+    pub hero_name: String,
+    /// Outcome of the last trade, shown on the shop screen.
+    pub shop_message: Option<String>,
+    pub combat_log: CombatLog,
+    /// Problem to report to the player, shown at the bottom of the screen until the next key press.
+    pub notice: Option<String>,
+
+    pub game: GameState,
+    pub campaign: Campaign,
+
+    /// Seed for the next run. A random one is rolled when none is given.
+    pub seed: Option<u64>,
 }
 
 impl App {
     pub fn run(&mut self, mut terminal: DefaultTerminal) -> Result<()> {
+        let mut last_tick = Instant::now();
+
         loop {
             terminal.draw(|frame| self.draw(frame))?;
 
-            let has_waited_list = [
-                self.wait(&Screen::RoomLoading, 1, Screen::Room),
-                self.wait(&Screen::DungeonLoading, 1, Screen::RoomLoading),
-                self.wait(&Screen::CombatLoading, 1, Screen::Combat),
-                self.wait(&Screen::DefeatMonster, 1, Screen::RoomResult),
-                self.wait(&Screen::DeadPlayer, 5, Screen::MainMenu),
-                self.wait(&Screen::RunScreen, 1, Screen::MainMenu),
-            ];
-
-            if has_waited_list.contains(&true) {
-                continue;
-            }
-
-            if let Event::Key(key) = event::read()? {
-                let mut is_quitting = false;
-                if key.kind == KeyEventKind::Press {
-                    match self.current_screen {
-                        Screen::MainMenu => is_quitting = self.handle_main_screen(key),
-                        Screen::Room => self.handle_room(key),
-                        Screen::Combat => self.handle_combat(key),
-                        Screen::RoomResult => self.handle_change_weapon(key),
-                        _ => (),
+            let timeout = TICK_RATE.saturating_sub(last_tick.elapsed());
+
+            if event::poll(timeout)? {
+                if let Event::Key(key) = event::read()? {
+                    let mut is_quitting = false;
+                    if key.kind == KeyEventKind::Press {
+                        self.notice = None;
+
+                        match self.current_screen {
+                            Screen::MainMenu => is_quitting = self.handle_main_screen(key),
+                            Screen::NameEntry => self.handle_name_entry(key),
+                            Screen::ClassSelection => self.handle_class_selection(key),
+                            Screen::ZoneSelection => self.handle_zone_selection(key),
+                            Screen::Room => self.handle_room(key),
+                            Screen::Combat => self.handle_combat(key),
+                            Screen::ItemPicker => self.handle_item_picker(key),
+                            Screen::SkillPicker => self.handle_skill_picker(key),
+                            Screen::RoomResult => self.handle_change_weapon(key),
+                            Screen::Shop => self.handle_shop(key),
+                            Screen::Map => self.handle_map(key),
+                            _ => self.skip_transition(),
+                        }
+
+                        // Failsafe
+                        if key.code == KeyCode::Esc {
+                            break;
+                        }
+
+                        self.persist();
                     }
 
-                    // Failsafe
-                    if key.code == KeyCode::Esc {
+                    if is_quitting {
                         break;
                     }
                 }
+            }
 
-                if is_quitting {
-                    break;
+            if last_tick.elapsed() >= TICK_RATE {
+                if self.tick(last_tick.elapsed()) {
+                    self.persist();
                 }
+                last_tick = Instant::now();
             }
         }
         Ok(())
@@ -75,44 +110,73 @@ impl App {
     pub fn draw(&mut self, frame: &mut Frame) {
         match self.current_screen {
             Screen::MainMenu => Screen::main_menu(frame, self),
+            Screen::NameEntry => Screen::name_entry(frame, self),
+            Screen::ClassSelection => Screen::class_selection(frame, self),
+            Screen::ZoneSelection => Screen::zone_selection(frame, self),
             Screen::DungeonLoading => Screen::dungeon_loading(frame, self),
             Screen::RoomLoading => Screen::room_loading(frame),
             Screen::RoomResult => Screen::room_result(frame, self),
             Screen::Combat => Screen::combat(frame, self),
+            Screen::ItemPicker => Screen::item_picker(frame, self),
+            Screen::SkillPicker => Screen::skill_picker(frame, self),
             Screen::Room => Screen::room(frame, self),
             Screen::CombatLoading => Screen::combat_loading(frame, self),
+            Screen::BossIntro => Screen::boss_intro(frame, self),
             Screen::DefeatMonster => Screen::defeat_monster(frame, self),
             Screen::DeadPlayer => Screen::dead_player(frame),
-            Screen::RunScreen => Screen::run_screen(frame),
+            Screen::ZoneCleared => Screen::zone_cleared(frame, self),
+            Screen::Shop => Screen::shop(frame, self),
+            Screen::Map => Screen::map(frame, self),
+            Screen::LevelUp => Screen::level_up(frame, self),
+            Screen::Escaped { gold_lost } => Screen::escaped(frame, gold_lost),
+        }
+
+        if let Some(notice) = &self.notice {
+            render_notice(frame, notice);
         }
     }
 
     pub const fn switch_screen(&mut self, screen: Screen) {
         self.current_screen = screen;
+        self.screen_elapsed = Duration::ZERO;
     }
 
-    pub fn create_player(&mut self) {
-        self.player = Player::new("You");
+    pub fn start_new_game(&mut self, zone: Zone) {
+        let seed = self.seed.unwrap_or_else(rand::random);
+
+        let ClassOption(class) = self.current_class_option;
+        let class = CharacterClass::ALL.get(class).copied().unwrap_or_default();
+        let player = Player::with_class(self.hero_name.trim(), class);
+
+        self.game = GameState::new_run(player, zone, seed);
+        self.combat_log.clear();
     }
 
-    #[allow(clippy::missing_panics_doc)]
-    pub fn create_dungeon(&mut self) {
-        let zone = Zone::get_available_zones()
-            .first()
-            .expect("Zone list should not be empty")
-            .clone();
+    /// Advances the timer of the current screen, moving on once a transitional
+    /// screen has been shown long enough. Returns whether the screen changed.
+    fn tick(&mut self, elapsed: Duration) -> bool {
+        self.screen_elapsed += elapsed;
+
+        match self.current_screen.transition() {
+            Some((duration, next_screen)) if self.screen_elapsed >= duration => {
+                self.switch_screen(next_screen);
+                true
+            }
+            _ => false,
+        }
+    }
 
-        self.dungeon = Dungeon::new(zone);
-        self.dungeon.start();
+    /// Saves the run without interrupting the game: a failed write is reported
+    /// to the player, and the next stable screen tries again.
+    fn persist(&mut self) {
+        if let Err(error) = self.persist_run() {
+            self.notice = Some(format!("Could not save the run: {error}"));
+        }
     }
 
-    fn wait(&mut self, room_to_wait: &Screen, secs: u64, room_to_switch_to: Screen) -> bool {
-        if &self.current_screen == room_to_wait {
-            thread::sleep(Duration::from_secs(secs));
-            self.switch_screen(room_to_switch_to);
-            true
-        } else {
-            false
+    fn skip_transition(&mut self) {
+        if let Some((_, next_screen)) = self.current_screen.transition() {
+            self.switch_screen(next_screen);
         }
     }
 }
@@ -0,0 +1,49 @@
+use std::{fs, io::ErrorKind};
+
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::zones::zone::Zone;
+
+pub const PROGRESS_FILE_PATH: &str = "ardentia_progress.json";
+
+/// Progress kept across runs: which zones have had their boss beaten.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Campaign {
+    /// Identifiers of the cleared zones.
+    pub cleared_zones: Vec<String>,
+}
+
+impl Campaign {
+    /// Reads the campaign progress, starting a fresh campaign when there is none yet.
+    pub fn load() -> Result<Self> {
+        match fs::read_to_string(PROGRESS_FILE_PATH) {
+            Ok(progress) => Ok(serde_json::from_str(&progress)?),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(PROGRESS_FILE_PATH, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn is_unlocked(&self, zone: &Zone) -> bool {
+        zone.unlocked_by
+            .as_ref()
+            .is_none_or(|required| self.is_cleared(required))
+    }
+
+    #[must_use]
+    pub fn is_cleared(&self, zone_id: &str) -> bool {
+        self.cleared_zones.iter().any(|cleared| cleared == zone_id)
+    }
+
+    pub fn mark_cleared(&mut self, zone_id: &str) {
+        if !self.is_cleared(zone_id) {
+            self.cleared_zones.push(zone_id.to_string());
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{entity::WeaponType, status::StatusKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CharacterClass {
+    #[default]
+    Warrior,
+    Rogue,
+    Mystic,
+}
+
+/// Stats a hero of the class starts the run with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartingStats {
+    pub health: i32,
+    pub attack: i32,
+    pub defence: i32,
+    pub speed: i32,
+    pub stamina: i32,
+}
+
+impl CharacterClass {
+    pub const ALL: [Self; 3] = [Self::Warrior, Self::Rogue, Self::Mystic];
+
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Warrior => "A sturdy fighter who stands their ground",
+            Self::Rogue => "A quick blade striking where it hurts",
+            Self::Mystic => "A wanderer drawing strength from within",
+        }
+    }
+
+    #[must_use]
+    pub const fn starting_stats(self) -> StartingStats {
+        match self {
+            Self::Warrior => StartingStats {
+                health: 14,
+                attack: 1,
+                defence: 1,
+                speed: 0,
+                stamina: 8,
+            },
+            Self::Rogue => StartingStats {
+                health: 9,
+                attack: 1,
+                defence: 0,
+                speed: 3,
+                stamina: 10,
+            },
+            Self::Mystic => StartingStats {
+                health: 10,
+                attack: 0,
+                defence: 0,
+                speed: 1,
+                stamina: 14,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn starting_weapon(self) -> WeaponType {
+        match self {
+            Self::Warrior => WeaponType::Sword,
+            Self::Rogue => WeaponType::Dagger,
+            Self::Mystic => WeaponType::Mace,
+        }
+    }
+
+    #[must_use]
+    pub const fn passive_name(self) -> &'static str {
+        match self {
+            Self::Warrior => "Iron Will",
+            Self::Rogue => "Opportunist",
+            Self::Mystic => "Attunement",
+        }
+    }
+
+    #[must_use]
+    pub const fn passive_description(self) -> &'static str {
+        match self {
+            Self::Warrior => "Cannot be stunned",
+            Self::Rogue => "10% more chance to land critical hits",
+            Self::Mystic => "Regenerates 1 health at the end of each turn in a fight",
+        }
+    }
+
+    /// Whether the class passive shrugs off the status.
+    #[must_use]
+    pub const fn resists(self, kind: StatusKind) -> bool {
+        matches!((self, kind), (Self::Warrior, StatusKind::Stun))
+    }
+
+    #[must_use]
+    pub const fn critical_bonus(self) -> f64 {
+        match self {
+            Self::Rogue => 0.1,
+            _ => 0.0,
+        }
+    }
+
+    /// Health recovered at the end of each of the hero's turns in a fight.
+    #[must_use]
+    pub const fn regeneration(self) -> i32 {
+        match self {
+            Self::Mystic => 1,
+            _ => 0,
+        }
+    }
+}
+
+impl Display for CharacterClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warrior => write!(f, "Warrior"),
+            Self::Rogue => write!(f, "Rogue"),
+            Self::Mystic => write!(f, "Mystic"),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{damage::Hit, game::GameEvent};
+
+/// What happened in the current room's fight, oldest first, as shown in the
+/// log panel of the combat screen.
+#[derive(Debug, Default, Clone)]
+pub struct CombatLog {
+    entries: Vec<String>,
+    /// How many entries the panel is scrolled back from the latest one.
+    scroll: usize,
+}
+
+impl CombatLog {
+    /// Adds the event to the log if it is part of a fight, and jumps back to the latest entry.
+    pub fn record(&mut self, event: &GameEvent) {
+        if let Some(entry) = describe(event) {
+            self.entries.push(entry);
+            self.scroll = 0;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.scroll = 0;
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = (self.scroll + 1).min(self.entries.len().saturating_sub(1));
+    }
+
+    pub const fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    /// The entries fitting in a panel of `height` lines, at the current scroll position.
+    #[must_use]
+    pub fn visible(&self, height: usize) -> &[String] {
+        let end = self.entries.len() - self.scroll.min(self.entries.len());
+        &self.entries[end.saturating_sub(height)..end]
+    }
+}
+
+fn describe(event: &GameEvent) -> Option<String> {
+    let entry = match event {
+        GameEvent::MonsterAppeared { name, level } => format!("A level {level} {name} appears."),
+        GameEvent::BossAppeared { name, level } => {
+            format!("{name}, level {level}, blocks the way!")
+        }
+        GameEvent::PlayerHit { name, hit } if !hit.landed => format!("The {name} misses you."),
+        GameEvent::PlayerHit { name, hit } => {
+            format!("The {name} hits you for {}{}.", hit.damage, critical(hit))
+        }
+        GameEvent::SpecialAttack { name, attack, hit } if !hit.landed => {
+            format!("The {name} uses {attack}, but misses.")
+        }
+        GameEvent::SpecialAttack { name, attack, hit } => {
+            format!(
+                "The {name} uses {attack} for {}{}!",
+                hit.damage,
+                critical(hit)
+            )
+        }
+        GameEvent::MonsterHit { name, hits: 0, .. } => format!("You miss the {name}."),
+        GameEvent::MonsterHit {
+            name,
+            damage,
+            hits,
+            critical,
+        } => {
+            let mut entry = format!("You hit the {name} for {damage}");
+            if *hits > 1 {
+                entry.push_str(&format!(" in {hits} strikes"));
+            }
+            if *critical {
+                entry.push_str(", a critical hit");
+            }
+            entry + "."
+        }
+        GameEvent::StatusApplied { name, status } => format!("{name} : {status}."),
+        GameEvent::StatusTicked {
+            name,
+            damage,
+            healed: 0,
+        } => format!("{name} : {damage} damage from statuses."),
+        GameEvent::StatusTicked {
+            name,
+            damage: 0,
+            healed,
+        } => format!("{name} : {healed} health regenerated."),
+        GameEvent::StatusTicked {
+            name,
+            damage,
+            healed,
+        } => format!("{name} : {damage} damage and {healed} health from statuses."),
+        GameEvent::StatusExpired { name, kind } => format!("{name} : {kind} wore off."),
+        GameEvent::TurnSkipped { name } => format!("{name} : stunned, turn lost."),
+        GameEvent::AttackParried { name } => format!("You parry the {name}'s attack."),
+        GameEvent::BossPhaseChanged { name, phase } => format!("{name} turns {phase}!"),
+        GameEvent::MonsterSlain { name, .. } => format!("The {name} is slain."),
+        GameEvent::ExperienceGained(experience) => format!("You gain {experience} XP."),
+        GameEvent::LevelUp { level } => format!("You reach level {level}!"),
+        GameEvent::SkillUsed { skill, healed: 0 } => format!("You use {skill}."),
+        GameEvent::SkillUsed { skill, healed } => {
+            format!("You use {skill} and recover {healed} health.")
+        }
+        GameEvent::ItemUsed { healed, .. } => {
+            format!("You drink a potion and recover {healed} health.")
+        }
+        GameEvent::FleeFailed => "You fail to get away.".to_string(),
+        GameEvent::PlayerDied => "You fall.".to_string(),
+        GameEvent::ItemCollected(_)
+        | GameEvent::GoldCollected(_)
+        | GameEvent::RoomCleared
+        | GameEvent::WeaponEquipped(_)
+        | GameEvent::ArmorEquipped(_)
+        | GameEvent::RoomEntered(_)
+        | GameEvent::ShopOpened
+        | GameEvent::ItemBought { .. }
+        | GameEvent::WeaponSold { .. }
+        | GameEvent::PurchaseFailed(_)
+        | GameEvent::ZoneCompleted { .. }
+        | GameEvent::PlayerEscaped { .. }
+        | GameEvent::MapOpened => return None,
+    };
+
+    Some(entry)
+}
+
+fn critical(hit: &Hit) -> &'static str {
+    if hit.critical {
+        ", a critical hit"
+    } else {
+        ""
+    }
+}
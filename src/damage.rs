@@ -0,0 +1,103 @@
+use rand::Rng;
+
+/// Chance to land a hit on a defender exactly as quick as the attacker.
+const BASE_ACCURACY: f64 = 0.9;
+/// Accuracy gained, or lost, per point of speed over the defender's.
+const ACCURACY_PER_SPEED: f64 = 0.02;
+const MIN_ACCURACY: f64 = 0.75;
+const MAX_ACCURACY: f64 = 0.98;
+/// Damage of a landed hit is rolled between these shares of its nominal value.
+const DAMAGE_VARIANCE: (f64, f64) = (0.85, 1.15);
+const CRITICAL_MULTIPLIER: f64 = 2.0;
+/// A hit that lands always hurts a little, whatever the defence.
+pub const MIN_DAMAGE: i32 = 1;
+
+/// A single blow about to be dealt, by the player or by a monster.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attack {
+    pub power: i32,
+    /// Speed of the attacker, weighed against the defender's to land the blow.
+    pub speed: i32,
+    pub damage_multiplier: f64,
+    /// Share of the defender's defence ignored, from 0 to 1.
+    pub defence_penetration: f64,
+    pub critical_chance: f64,
+    /// Minimum damage of the blow when it lands.
+    pub damage_floor: i32,
+    /// The blow cannot miss.
+    pub unerring: bool,
+}
+
+impl Attack {
+    #[must_use]
+    pub const fn new(power: i32, speed: i32) -> Self {
+        Self {
+            power,
+            speed,
+            damage_multiplier: 1.0,
+            defence_penetration: 0.0,
+            critical_chance: 0.0,
+            damage_floor: MIN_DAMAGE,
+            unerring: false,
+        }
+    }
+}
+
+/// What the receiving side brings against a blow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Defender {
+    pub defence: i32,
+    pub speed: i32,
+}
+
+impl Defender {
+    #[must_use]
+    pub const fn new(defence: i32, speed: i32) -> Self {
+        Self { defence, speed }
+    }
+}
+
+/// The outcome of a blow. A missed blow deals no damage.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Hit {
+    pub damage: i32,
+    pub landed: bool,
+    pub critical: bool,
+}
+
+/// Odds of landing a blow, better against slower defenders.
+#[must_use]
+pub fn accuracy(attacker_speed: i32, defender_speed: i32) -> f64 {
+    f64::from(attacker_speed - defender_speed)
+        .mul_add(ACCURACY_PER_SPEED, BASE_ACCURACY)
+        .clamp(MIN_ACCURACY, MAX_ACCURACY)
+}
+
+/// Rolls a blow against a defender: whether it lands, how hard, and whether it is critical.
+/// Both the player and the monsters go through here.
+pub fn resolve<R: Rng + ?Sized>(attack: &Attack, defender: &Defender, rng: &mut R) -> Hit {
+    if !attack.unerring && !rng.random_bool(accuracy(attack.speed, defender.speed)) {
+        return Hit::default();
+    }
+
+    let defence =
+        f64::from(defender.defence.max(0)) * (1.0 - attack.defence_penetration.clamp(0.0, 1.0));
+    let variance = rng.random_range(DAMAGE_VARIANCE.0..=DAMAGE_VARIANCE.1);
+    let mut damage = f64::from(attack.power)
+        .mul_add(attack.damage_multiplier * variance, -defence)
+        .max(0.0);
+
+    let critical = rng.random_bool(attack.critical_chance.clamp(0.0, 1.0));
+    if critical {
+        damage *= CRITICAL_MULTIPLIER;
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let damage = (damage.round() as i32).max(attack.damage_floor);
+
+    Hit {
+        damage,
+        landed: true,
+        critical,
+    }
+}
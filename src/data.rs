@@ -0,0 +1,93 @@
+use std::{fs, io::ErrorKind, path::Path, sync::OnceLock};
+
+use color_eyre::{eyre::WrapErr, Result};
+use serde::de::DeserializeOwned;
+
+/// Game data read from a directory of `.json` files at startup, so it can be
+/// tuned without rebuilding. Copies of the shipped files are embedded in the
+/// binary for when the directory is not there.
+pub trait DataSet: Sized {
+    type Definition: DeserializeOwned;
+
+    const DIR: &'static str;
+    const BUILTIN: &'static [&'static str];
+
+    /// Checks the definitions and builds the set from them.
+    fn from_definitions(definitions: Vec<Self::Definition>) -> Result<Self>;
+}
+
+/// Loads a data set from a directory, or from its embedded copies when the
+/// directory does not exist. Unreadable or invalid files are errors: they are
+/// never silently replaced by the built-in data.
+pub fn load<T: DataSet, P: AsRef<Path>>(dir: P) -> Result<T> {
+    let dir = dir.as_ref();
+
+    let definitions = match fs::read_dir(dir) {
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            parse_json_sources(T::BUILTIN).wrap_err("Invalid built-in data")?
+        }
+        _ => load_json_dir(dir)?,
+    };
+
+    T::from_definitions(definitions).wrap_err_with(|| format!("Invalid data in {}", dir.display()))
+}
+
+/// The copy of a data set shared by the whole game, loaded on first use
+/// unless `init_global` loaded it beforehand.
+///
+/// # Panics
+///
+/// When the data loaded on first use is invalid. Call `init_global` at startup
+/// to get the error instead.
+pub fn global<T: DataSet>(cell: &'static OnceLock<T>) -> &'static T {
+    cell.get_or_init(|| load(T::DIR).unwrap_or_else(|error| panic!("{error:?}")))
+}
+
+/// Loads the shared copy of a data set ahead of its first use.
+pub fn init_global<T: DataSet>(cell: &'static OnceLock<T>) -> Result<()> {
+    if cell.get().is_none() {
+        let _ = cell.set(load(T::DIR)?);
+    }
+
+    Ok(())
+}
+
+/// Parses every `.json` file of a directory, in file name order.
+pub fn load_json_dir<T: DeserializeOwned, P: AsRef<Path>>(dir: P) -> Result<Vec<T>> {
+    let dir = dir.as_ref();
+    let mut paths = Vec::new();
+
+    for entry in fs::read_dir(dir).wrap_err_with(|| format!("Could not read {}", dir.display()))? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut items = Vec::with_capacity(paths.len());
+    for path in paths {
+        let source = fs::read_to_string(&path)
+            .wrap_err_with(|| format!("Could not read {}", path.display()))?;
+        items.push(
+            serde_json::from_str(&source)
+                .wrap_err_with(|| format!("Could not parse {}", path.display()))?,
+        );
+    }
+
+    Ok(items)
+}
+
+/// Parses data files embedded in the binary with `include_str!`.
+pub fn parse_json_sources<T: DeserializeOwned>(sources: &[&str]) -> Result<Vec<T>> {
+    let mut items = Vec::with_capacity(sources.len());
+
+    for source in sources {
+        items.push(serde_json::from_str(source)?);
+    }
+
+    Ok(items)
+}
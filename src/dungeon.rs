@@ -1,49 +1,79 @@
 use crate::{
     room::Room,
-    zones::{generator::RoomGenerator, zone::Zone},
+    zones::{
+        generator::RoomGenerator,
+        map::{DungeonMap, MapNode},
+        zone::Zone,
+    },
 };
 
 #[derive(Default, Debug)]
 pub struct Dungeon {
     pub current_zone: Zone,
     pub current_room_number: i32,
+    /// Column of the current room on the map.
+    pub current_column: usize,
+    pub map: DungeonMap,
     pub room_generator: RoomGenerator,
     pub is_active: bool,
     pub current_room: Room,
 }
 
-const MAX_MONSTER_NUMBER: i32 = 10;
-
 impl Dungeon {
     #[must_use]
-    pub fn new(zone: Zone) -> Self {
+    pub fn new(zone: Zone, seed: u64) -> Self {
+        let room_generator = RoomGenerator::new(seed);
+        let map = DungeonMap::generate(&zone, &room_generator);
+
         Self {
             current_zone: zone,
             current_room_number: 1,
-            room_generator: RoomGenerator::new(),
+            current_column: map.entrance(),
+            map,
+            room_generator,
             current_room: Room::default(),
             is_active: true,
         }
     }
 
     pub fn start(&mut self) {
-        self.current_room = self
-            .room_generator
-            .generate_room(&self.current_zone, self.current_room_number);
+        self.current_room = self.generate_current_room();
     }
 
     #[must_use]
     pub fn generate_current_room(&self) -> Room {
-        self.room_generator
-            .generate_room(&self.current_zone, self.current_room_number)
+        self.room_generator.generate_room(
+            &self.current_zone,
+            self.current_room_number,
+            self.current_column,
+        )
     }
 
-    pub fn next_room(&mut self) {
-        self.current_room_number += 1;
+    /// The rooms of the next layer the current room leads to.
+    #[must_use]
+    pub fn next_rooms(&self) -> Vec<&MapNode> {
+        let Some(current) = self.map.node(self.current_room_number, self.current_column) else {
+            return Vec::new();
+        };
+
+        self.map
+            .layer(self.current_room_number + 1)
+            .iter()
+            .filter(|node| current.next.contains(&node.column))
+            .collect()
+    }
+
+    /// Moves on to the room at this column of the next layer, if the current
+    /// room leads there. Returns whether the player moved.
+    pub fn next_room(&mut self, column: usize) -> bool {
+        if !self.next_rooms().iter().any(|node| node.column == column) {
+            return false;
+        }
 
-        self.current_room = self
-            .room_generator
-            .generate_room(&self.current_zone, self.current_room_number);
+        self.current_room_number += 1;
+        self.current_column = column;
+        self.current_room = self.generate_current_room();
+        true
     }
 
     pub const fn complete_zone(&mut self) {
@@ -54,8 +84,9 @@ impl Dungeon {
         self.is_active = false;
     }
 
-    pub const fn is_there_rooms_left(&mut self) -> bool {
-        self.current_room_number < MAX_MONSTER_NUMBER
+    #[must_use]
+    pub const fn is_there_rooms_left(&self) -> bool {
+        self.current_room_number < self.current_zone.room_count
     }
 
     pub const fn get_current_room_mutable(&mut self) -> &mut Room {
@@ -0,0 +1,66 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// Returned when a payment is larger than the balance; nothing is charged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsufficientFunds {
+    pub price: u32,
+    pub balance: u32,
+}
+
+impl Display for InsufficientFunds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Not enough gold: {} needed, {} available",
+            self.price, self.balance
+        )
+    }
+}
+
+impl std::error::Error for InsufficientFunds {}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Wallet {
+    gold: u32,
+}
+
+impl Wallet {
+    #[must_use]
+    pub const fn balance(&self) -> u32 {
+        self.gold
+    }
+
+    #[must_use]
+    pub const fn can_afford(&self, price: u32) -> bool {
+        self.gold >= price
+    }
+
+    pub const fn deposit(&mut self, amount: u32) {
+        self.gold = self.gold.saturating_add(amount);
+    }
+
+    pub const fn withdraw(&mut self, amount: u32) -> Result<(), InsufficientFunds> {
+        if !self.can_afford(amount) {
+            return Err(InsufficientFunds {
+                price: amount,
+                balance: self.gold,
+            });
+        }
+
+        self.gold -= amount;
+        Ok(())
+    }
+
+    /// Charges `price` and hands over what was bought, or charges nothing if
+    /// the balance is too low. Shops and services should go through this.
+    pub fn pay_for<T, F: FnOnce() -> T>(
+        &mut self,
+        price: u32,
+        purchase: F,
+    ) -> Result<T, InsufficientFunds> {
+        self.withdraw(price)?;
+        Ok(purchase())
+    }
+}
@@ -4,19 +4,60 @@ use std::{
 };
 
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    class::CharacterClass,
+    damage::{self, Attack, Defender},
+    economy::Wallet,
+    equipment::Equipment,
+    inventory::Inventory,
+    loot::{Affix, AffixPosition},
+    monsters::Monster,
+    skills::{Cooldowns, Skill},
+    status::{OnHitEffect, StatusEffect, StatusEffects, StatusKind},
+};
 
-use crate::monsters::Monster;
+/// Stats gained on every level up. Defence and speed grow every other level.
+const LEVEL_UP_HEALTH: i32 = 3;
+const LEVEL_UP_ATTACK: i32 = 1;
+const LEVEL_UP_STAMINA: i32 = 1;
+/// Stamina recovered at the end of each of the player's turns.
+const STAMINA_REGEN: i32 = 1;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Player {
     pub name: String,
+    pub class: CharacterClass,
+    pub level: i32,
+    /// Experience earned towards the next level.
+    pub experience: u32,
     pub max_health: i32,
     pub health: i32,
     pub attack: i32,
     pub defence: i32,
     pub speed: i32,
+    pub max_stamina: i32,
+    /// Spent on skills, recovered over the turns and fully after each fight.
+    pub stamina: i32,
+    pub cooldowns: Cooldowns,
 
     pub weapon: Option<Weapon>,
+    pub equipment: Equipment,
+    pub inventory: Inventory,
+    pub wallet: Wallet,
+    pub statuses: StatusEffects,
+}
+
+/// What a player attack did over the turn.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Strike {
+    pub damage: i32,
+    /// Strikes that landed, none when the attack missed.
+    pub hits: u32,
+    pub critical: bool,
+    /// Statuses rolled by the weapon, to apply to the monster or, when beneficial, to the player.
+    pub inflicted: Vec<StatusEffect>,
 }
 
 #[derive(Debug)]
@@ -25,22 +66,25 @@ pub enum Action {
     Run,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Weapon {
     pub name: String,
     pub weapon_type: WeaponType,
     pub attack_value: i32,
     pub rarity: Rarity,
+    pub affixes: Vec<Affix>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum WeaponType {
     Sword,
     Dagger,
     Axe,
+    Spear,
+    Mace,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Rarity {
     Common,
     Rare,
@@ -49,12 +93,91 @@ pub enum Rarity {
     Mythical,
 }
 
+impl WeaponType {
+    pub const ALL: [Self; 5] = [
+        Self::Sword,
+        Self::Dagger,
+        Self::Axe,
+        Self::Spear,
+        Self::Mace,
+    ];
+
+    #[must_use]
+    pub const fn base_attack(&self) -> i32 {
+        match self {
+            Self::Sword => 10,
+            Self::Dagger => 5,
+            Self::Axe => 15,
+            Self::Spear => 11,
+            Self::Mace => 13,
+        }
+    }
+
+    /// Chance for a strike to deal double damage.
+    #[must_use]
+    pub const fn critical_chance(&self) -> f64 {
+        match self {
+            Self::Dagger => 0.3,
+            _ => 0.05,
+        }
+    }
+
+    /// Chance to strike a second time in the same turn.
+    #[must_use]
+    pub const fn double_strike_chance(&self) -> f64 {
+        match self {
+            Self::Dagger => 0.25,
+            _ => 0.0,
+        }
+    }
+
+    /// Share of the monster's defence ignored by the strike.
+    #[must_use]
+    pub const fn defence_penetration(&self) -> f64 {
+        match self {
+            Self::Axe => 0.5,
+            _ => 0.0,
+        }
+    }
+
+    /// Chance to parry the monster's attack and take no damage.
+    #[must_use]
+    pub const fn parry_chance(&self) -> f64 {
+        match self {
+            Self::Sword => 0.2,
+            _ => 0.0,
+        }
+    }
+
+    /// Status a hit may inflict: maces can stun the monster for its next turn.
+    #[must_use]
+    pub const fn on_hit_effect(&self) -> Option<OnHitEffect> {
+        match self {
+            Self::Mace => Some(OnHitEffect {
+                kind: StatusKind::Stun,
+                chance: 0.2,
+                potency: 0,
+                turns: 1,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Spears keep monsters at bay: their wielder always strikes first.
+    #[must_use]
+    pub const fn strikes_first(&self) -> bool {
+        matches!(self, Self::Spear)
+    }
+}
+
 impl fmt::Display for WeaponType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Sword => write!(f, "Sword"),
             Self::Dagger => write!(f, "Dagger"),
             Self::Axe => write!(f, "Axe"),
+            Self::Spear => write!(f, "Spear"),
+            Self::Mace => write!(f, "Mace"),
         }
     }
 }
@@ -72,34 +195,29 @@ impl fmt::Display for Rarity {
 }
 
 impl Weapon {
+    /// Builds a weapon whose name comes from its type and affixes, e.g. "Venomous Axe of Haste".
     #[must_use]
-    pub fn new(weapon_type: WeaponType) -> Self {
-        let prefix = ["Broken", "Rusty", "", "Sharp"];
-
-        let weapon_prefix = prefix[rand::rng().random_range(0..prefix.len())];
-
-        let name = format!("{} {}", weapon_prefix, WeaponType::Sword);
-
-        let mut attack_value = match weapon_type {
-            WeaponType::Sword => 10,
-            WeaponType::Dagger => 5,
-            WeaponType::Axe => 15,
-        };
-
-        attack_value = match weapon_prefix {
-            "Broken" => attack_value / 2,
-            "Rusty" => attack_value - 2,
-            "Sharp" => attack_value + 2,
-            _ => attack_value,
-        };
-
-        let rarity = Rarity::Common;
+    pub fn new(
+        weapon_type: WeaponType,
+        rarity: Rarity,
+        attack_value: i32,
+        affixes: Vec<Affix>,
+    ) -> Self {
+        let mut name = weapon_type.to_string();
+
+        for affix in &affixes {
+            name = match affix.position {
+                AffixPosition::Prefix => format!("{} {name}", affix.name),
+                AffixPosition::Suffix => format!("{name} {}", affix.name),
+            };
+        }
 
         Self {
             name,
             weapon_type,
             attack_value,
             rarity,
+            affixes,
         }
     }
 
@@ -110,13 +228,32 @@ impl Weapon {
             weapon_type: WeaponType::Sword,
             attack_value: 0,
             rarity: Rarity::Common,
+            affixes: Vec::new(),
         }
     }
+
+    #[must_use]
+    pub fn defence_bonus(&self) -> i32 {
+        self.affixes.iter().map(|affix| affix.defence).sum()
+    }
+
+    #[must_use]
+    pub fn speed_bonus(&self) -> i32 {
+        self.affixes.iter().map(|affix| affix.speed).sum()
+    }
+
+    /// Statuses a hit may inflict, from the weapon type and its affixes.
+    pub fn on_hit_effects(&self) -> impl Iterator<Item = OnHitEffect> + '_ {
+        self.weapon_type
+            .on_hit_effect()
+            .into_iter()
+            .chain(self.affixes.iter().filter_map(|affix| affix.effect))
+    }
 }
 
 impl Display for Weapon {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "A {} {}", self.rarity, self.weapon_type)
+        write!(f, "A {} {}", self.rarity, self.name)
     }
 }
 
@@ -125,13 +262,50 @@ impl Player {
     pub fn new<S: Into<String>>(name: S) -> Self {
         Self {
             name: name.into(),
+            class: CharacterClass::default(),
+            level: 1,
+            experience: 0,
             max_health: 10,
             health: 10,
             attack: 1,
             defence: 0,
             speed: 1,
+            max_stamina: 10,
+            stamina: 10,
+            cooldowns: Cooldowns::default(),
 
             weapon: None,
+            equipment: Equipment::default(),
+            inventory: Inventory::default(),
+            wallet: Wallet::default(),
+            statuses: StatusEffects::default(),
+        }
+    }
+
+    /// A hero with the class's starting stats, holding a worn weapon of the
+    /// class's type that is half as strong as a common one.
+    #[must_use]
+    pub fn with_class<S: Into<String>>(name: S, class: CharacterClass) -> Self {
+        let stats = class.starting_stats();
+        let weapon_type = class.starting_weapon();
+        let attack_value = weapon_type.base_attack() / 2;
+
+        Self {
+            class,
+            max_health: stats.health,
+            health: stats.health,
+            attack: stats.attack,
+            defence: stats.defence,
+            speed: stats.speed,
+            max_stamina: stats.stamina,
+            stamina: stats.stamina,
+            weapon: Some(Weapon::new(
+                weapon_type,
+                Rarity::Common,
+                attack_value,
+                Vec::new(),
+            )),
+            ..Self::new(name)
         }
     }
 
@@ -152,20 +326,72 @@ impl Player {
         }
     }
 
-    pub fn attack(&self, target: &mut dyn Monster) {
-        let target_stats = target.get_stats();
+    pub fn attack<R: Rng>(&self, target: &mut dyn Monster, rng: &mut R) -> Strike {
+        self.attack_with(target, rng, 1.0, 0.0)
+    }
 
-        let damage = match self.weapon.as_ref() {
-            Some(weapon) => (self.attack + weapon.attack_value) - target_stats.defence,
-            None => self.attack - target_stats.defence,
+    /// An attack boosted by a skill: its damage is multiplied and it ignores
+    /// an extra share of the monster's defence.
+    pub fn attack_with<R: Rng>(
+        &self,
+        target: &mut dyn Monster,
+        rng: &mut R,
+        damage_multiplier: f64,
+        defence_penetration: f64,
+    ) -> Strike {
+        let weapon_type = self.weapon.as_ref().map(|weapon| &weapon.weapon_type);
+        let chance = |roll: fn(&WeaponType) -> f64| weapon_type.map_or(0.0, roll);
+
+        let strikes = if rng.random_bool(chance(WeaponType::double_strike_chance)) {
+            2
+        } else {
+            1
         };
-
-        let damage: i32 = match damage {
-            x if x < 0 => 0,
-            x => x,
+        let attack = Attack {
+            damage_multiplier,
+            defence_penetration: chance(WeaponType::defence_penetration) + defence_penetration,
+            critical_chance: chance(WeaponType::critical_chance) + self.class.critical_bonus(),
+            ..Attack::new(self.get_attack(), self.get_speed())
         };
+        let mut strike = Strike::default();
+
+        for _ in 0..strikes {
+            let hit = damage::resolve(&attack, &target.defender(), rng);
+            if hit.landed {
+                target.take_damage(hit.damage);
+                strike.damage += hit.damage;
+                strike.hits += 1;
+                strike.critical |= hit.critical;
+            }
+
+            if !target.is_alive() {
+                break;
+            }
+        }
+
+        if strike.damage > 0 {
+            if let Some(weapon) = &self.weapon {
+                strike.inflicted = weapon
+                    .on_hit_effects()
+                    .filter_map(|effect| effect.roll(rng))
+                    .collect();
+            }
+        }
+        strike
+    }
 
-        target.take_damage(damage);
+    /// Whether the equipped weapon turns the monster's next attack aside.
+    pub fn parries<R: Rng>(&self, rng: &mut R) -> bool {
+        self.weapon
+            .as_ref()
+            .is_some_and(|weapon| rng.random_bool(weapon.weapon_type.parry_chance()))
+    }
+
+    #[must_use]
+    pub fn strikes_first(&self) -> bool {
+        self.weapon
+            .as_ref()
+            .is_some_and(|weapon| weapon.weapon_type.strikes_first())
     }
 
     #[must_use]
@@ -176,7 +402,28 @@ impl Player {
             attack_value += x.attack_value;
         }
 
-        attack_value
+        (attack_value - self.statuses.potency(StatusKind::Weakness)).max(0)
+    }
+
+    /// Defence including the bonuses of the equipped weapon and armor.
+    #[must_use]
+    pub fn get_defence(&self) -> i32 {
+        self.defence
+            + self.equipment.defence_bonus()
+            + self.weapon.as_ref().map_or(0, Weapon::defence_bonus)
+    }
+
+    /// Speed including the bonuses of the equipped weapon and armor.
+    #[must_use]
+    pub fn get_speed(&self) -> i32 {
+        self.speed
+            + self.equipment.speed_bonus()
+            + self.weapon.as_ref().map_or(0, Weapon::speed_bonus)
+    }
+
+    #[must_use]
+    pub fn defender(&self) -> Defender {
+        Defender::new(self.get_defence(), self.get_speed())
     }
 
     #[must_use]
@@ -184,17 +431,90 @@ impl Player {
         self.health <= 0
     }
 
+    /// Restores health up to the maximum and returns how much was actually healed.
+    pub fn heal(&mut self, amount: i32) -> i32 {
+        let healed = amount.min(self.max_health - self.health).max(0);
+        self.health += healed;
+        healed
+    }
+
+    /// Whether the skill is off cooldown and affordable.
+    #[must_use]
+    pub fn can_use_skill(&self, skill: Skill) -> bool {
+        self.cooldowns.remaining(skill) == 0 && self.stamina >= skill.cost()
+    }
+
+    /// Spends the skill's stamina and puts it on cooldown.
+    pub fn use_skill(&mut self, skill: Skill) {
+        self.stamina -= skill.cost();
+        self.cooldowns.start(skill);
+    }
+
+    /// Counts down cooldowns and recovers some stamina at the end of a turn.
+    pub fn recover(&mut self) {
+        self.cooldowns.tick();
+        self.stamina = (self.stamina + STAMINA_REGEN).min(self.max_stamina);
+    }
+
+    /// Catches breath once the fight is over.
+    pub fn rest(&mut self) {
+        self.cooldowns.clear();
+        self.stamina = self.max_stamina;
+    }
+
     pub fn equip(&mut self, weapon: Weapon) {
         self.weapon = Some(weapon);
     }
+
+    #[must_use]
+    pub const fn experience_to_next_level(&self) -> u32 {
+        experience_for_level(self.level)
+    }
+
+    /// Adds experience, levelling up as many times as it allows.
+    /// Returns the number of levels gained.
+    pub fn gain_experience(&mut self, amount: u32) -> i32 {
+        let mut levels_gained = 0;
+
+        self.experience += amount;
+        while self.experience >= self.experience_to_next_level() {
+            self.experience -= self.experience_to_next_level();
+            self.level_up();
+            levels_gained += 1;
+        }
+
+        levels_gained
+    }
+
+    const fn level_up(&mut self) {
+        self.level += 1;
+        self.max_health += LEVEL_UP_HEALTH;
+        self.health += LEVEL_UP_HEALTH;
+        self.attack += LEVEL_UP_ATTACK;
+        self.max_stamina += LEVEL_UP_STAMINA;
+        self.stamina += LEVEL_UP_STAMINA;
+
+        if self.level % 2 == 0 {
+            self.defence += 1;
+        } else {
+            self.speed += 1;
+        }
+    }
+}
+
+/// Experience needed to go from `level` to the next one.
+#[must_use]
+pub const fn experience_for_level(level: i32) -> u32 {
+    let previous = level.unsigned_abs().saturating_sub(1);
+    10 + 10 * previous + 5 * previous * previous
 }
 
 impl fmt::Display for Player {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "===== {} =====\nHealth: {}\nAttack: {}\nDefense: {}\nSpeed: {}\n",
-            self.name, self.health, self.attack, self.defence, self.speed
+            "===== {} (level {}) =====\nHealth: {}\nAttack: {}\nDefense: {}\nSpeed: {}\n",
+            self.name, self.level, self.health, self.attack, self.defence, self.speed
         )
     }
 }
@@ -0,0 +1,151 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::entity::Rarity;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArmorSlot {
+    Head,
+    Body,
+    Shield,
+    Accessory,
+}
+
+impl ArmorSlot {
+    pub const ALL: [Self; 4] = [Self::Head, Self::Body, Self::Shield, Self::Accessory];
+
+    #[must_use]
+    pub const fn base_defence(&self) -> i32 {
+        match self {
+            Self::Head => 1,
+            Self::Body => 3,
+            Self::Shield => 2,
+            Self::Accessory => 0,
+        }
+    }
+
+    /// Heavy pieces slow the player down, accessories make them quicker.
+    #[must_use]
+    pub const fn base_speed(&self) -> i32 {
+        match self {
+            Self::Head => 0,
+            Self::Body | Self::Shield => -1,
+            Self::Accessory => 1,
+        }
+    }
+
+    #[must_use]
+    pub const fn item_name(&self) -> &'static str {
+        match self {
+            Self::Head => "Helmet",
+            Self::Body => "Chestplate",
+            Self::Shield => "Shield",
+            Self::Accessory => "Amulet",
+        }
+    }
+}
+
+impl Display for ArmorSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Head => write!(f, "Head"),
+            Self::Body => write!(f, "Body"),
+            Self::Shield => write!(f, "Shield"),
+            Self::Accessory => write!(f, "Accessory"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Armor {
+    pub name: String,
+    pub slot: ArmorSlot,
+    pub rarity: Rarity,
+    pub defence: i32,
+    pub speed: i32,
+}
+
+impl Armor {
+    #[must_use]
+    pub fn new(slot: ArmorSlot, rarity: Rarity, defence: i32, speed: i32) -> Self {
+        Self {
+            name: slot.item_name().to_string(),
+            slot,
+            rarity,
+            defence,
+            speed,
+        }
+    }
+
+    /// Rough worth of the piece, used to tell whether it beats the one worn.
+    #[must_use]
+    pub const fn score(&self) -> i32 {
+        self.defence * 2 + self.speed
+    }
+}
+
+impl Display for Armor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "A {} {} ({} defence, {:+} speed)",
+            self.rarity, self.name, self.defence, self.speed
+        )
+    }
+}
+
+/// The armor pieces worn by the player, one per slot.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Equipment {
+    pub head: Option<Armor>,
+    pub body: Option<Armor>,
+    pub shield: Option<Armor>,
+    pub accessory: Option<Armor>,
+}
+
+impl Equipment {
+    #[must_use]
+    pub const fn get(&self, slot: ArmorSlot) -> Option<&Armor> {
+        match slot {
+            ArmorSlot::Head => self.head.as_ref(),
+            ArmorSlot::Body => self.body.as_ref(),
+            ArmorSlot::Shield => self.shield.as_ref(),
+            ArmorSlot::Accessory => self.accessory.as_ref(),
+        }
+    }
+
+    const fn slot_mut(&mut self, slot: ArmorSlot) -> &mut Option<Armor> {
+        match slot {
+            ArmorSlot::Head => &mut self.head,
+            ArmorSlot::Body => &mut self.body,
+            ArmorSlot::Shield => &mut self.shield,
+            ArmorSlot::Accessory => &mut self.accessory,
+        }
+    }
+
+    /// Wears the piece in its slot and returns the one it replaces.
+    pub const fn equip(&mut self, armor: Armor) -> Option<Armor> {
+        self.slot_mut(armor.slot).replace(armor)
+    }
+
+    #[must_use]
+    pub fn is_upgrade(&self, armor: &Armor) -> bool {
+        self.get(armor.slot)
+            .is_none_or(|worn| armor.score() > worn.score())
+    }
+
+    pub fn pieces(&self) -> impl Iterator<Item = &Armor> {
+        ArmorSlot::ALL.into_iter().filter_map(|slot| self.get(slot))
+    }
+
+    #[must_use]
+    pub fn defence_bonus(&self) -> i32 {
+        self.pieces().map(|armor| armor.defence).sum()
+    }
+
+    #[must_use]
+    pub fn speed_bonus(&self) -> i32 {
+        self.pieces().map(|armor| armor.speed).sum()
+    }
+}
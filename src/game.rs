@@ -0,0 +1,828 @@
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    damage::{self, Hit},
+    dungeon::Dungeon,
+    economy::InsufficientFunds,
+    entity::{Player, Weapon},
+    equipment::Armor,
+    initiative::{Combatant, Timeline},
+    inventory::Consumable,
+    monsters::Monster,
+    room::{Room, WeaponUtils},
+    shop::{weapon_sell_price, ShopItem},
+    skills::Skill,
+    status::{StatusEffect, StatusEffects, StatusKind, StatusTick},
+    zones::zone::Zone,
+};
+
+const COMBAT_SALT: u64 = 0x5DEE_CE66_D1CE_4E5B;
+
+/// Everything a run needs to be played, without any knowledge of the terminal.
+#[derive(Default, Debug)]
+pub struct GameState {
+    pub player: Player,
+    pub dungeon: Dungeon,
+    /// Turn order of the fight in progress, empty outside of fights.
+    pub timeline: Timeline,
+    /// Rolls of the fights, saved with the run so that it plays out the same after loading.
+    pub combat_rng: CombatRng,
+    /// Where the run stands, which decides the commands it accepts.
+    pub phase: Phase,
+    /// Experience of the monsters slain since the round started.
+    pub(crate) experience_earned: u32,
+}
+
+/// The stages a run goes through in each room.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Phase {
+    /// At the door of the current room.
+    #[default]
+    Exploring,
+    Fighting,
+    /// The room is cleared and its treasure waits to be collected.
+    Looting,
+    Shopping,
+    /// The room is behind the player, who picks the next one on the map.
+    ChoosingPath,
+    /// The player died or cleared the zone.
+    Over,
+}
+
+/// Generator of every combat roll, derived from the run seed. It is the
+/// algorithm behind `StdRng`, whose state can be saved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CombatRng(ChaCha12Rng);
+
+impl CombatRng {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self(ChaCha12Rng::seed_from_u64(seed ^ COMBAT_SALT))
+    }
+}
+
+impl Default for CombatRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl RngCore for CombatRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameCommand {
+    EnterRoom,
+    /// Attacks the monster at this index of the current room.
+    Attack(usize),
+    /// Uses a skill, striking the monster at `target` if the skill is an attack.
+    UseSkill {
+        skill: Skill,
+        target: usize,
+    },
+    /// Uses the consumable at this index of the player's inventory.
+    UseItem(usize),
+    Run,
+    EquipWeapon,
+    KeepWeapon,
+    /// Buys the item at this index of the merchant's stock.
+    Buy(usize),
+    SellWeapon,
+    LeaveShop,
+    /// Moves on to the room at this column of the next layer of the map.
+    ChooseRoom(usize),
+}
+
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    MonsterAppeared {
+        name: String,
+        level: i32,
+    },
+    BossAppeared {
+        name: String,
+        level: i32,
+    },
+    PlayerHit {
+        name: String,
+        hit: Hit,
+    },
+    SpecialAttack {
+        name: String,
+        attack: String,
+        hit: Hit,
+    },
+    MonsterHit {
+        name: String,
+        damage: i32,
+        hits: u32,
+        critical: bool,
+    },
+    StatusApplied {
+        name: String,
+        status: StatusEffect,
+    },
+    /// Damage and healing dealt by the statuses at the end of a turn.
+    StatusTicked {
+        name: String,
+        damage: i32,
+        healed: i32,
+    },
+    StatusExpired {
+        name: String,
+        kind: StatusKind,
+    },
+    /// A stunned combatant lost its turn.
+    TurnSkipped {
+        name: String,
+    },
+    AttackParried {
+        name: String,
+    },
+    BossPhaseChanged {
+        name: String,
+        phase: String,
+    },
+    MonsterSlain {
+        name: String,
+        level: i32,
+    },
+    ExperienceGained(u32),
+    LevelUp {
+        level: i32,
+    },
+    SkillUsed {
+        skill: Skill,
+        healed: i32,
+    },
+    ItemUsed {
+        item: String,
+        healed: i32,
+    },
+    ItemCollected(Consumable),
+    GoldCollected(u32),
+    RoomCleared,
+    WeaponEquipped(Weapon),
+    ArmorEquipped(Armor),
+    RoomEntered(i32),
+    /// The room is behind the player, who now picks the next one on the map.
+    MapOpened,
+    ShopOpened,
+    ItemBought {
+        item: String,
+        price: u32,
+    },
+    WeaponSold {
+        weapon: String,
+        price: u32,
+    },
+    PurchaseFailed(InsufficientFunds),
+    ZoneCompleted {
+        zone: String,
+    },
+    PlayerDied,
+    FleeFailed,
+    /// The player got away from the room, dropping some gold.
+    PlayerEscaped {
+        gold_lost: u32,
+    },
+}
+
+impl GameState {
+    #[must_use]
+    pub fn new(player: Player, dungeon: Dungeon) -> Self {
+        let combat_rng = CombatRng::new(dungeon.room_generator.seed());
+
+        Self {
+            player,
+            dungeon,
+            timeline: Timeline::new(),
+            combat_rng,
+            phase: Phase::Exploring,
+            experience_earned: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn new_run(player: Player, zone: Zone, seed: u64) -> Self {
+        let mut dungeon = Dungeon::new(zone, seed);
+        dungeon.start();
+
+        Self::new(player, dungeon)
+    }
+
+    /// Applies a command to the run and reports what happened, in order.
+    /// Commands that do not belong to the current phase produce no events.
+    pub fn apply(&mut self, command: GameCommand) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+
+        match (self.phase, command) {
+            (Phase::Exploring, GameCommand::EnterRoom) => self.enter_room(&mut events),
+            (Phase::Fighting, GameCommand::Attack(target)) => {
+                self.attack(target, None, &mut events);
+            }
+            (Phase::Fighting, GameCommand::UseSkill { skill, target }) => {
+                self.use_skill(skill, target, &mut events);
+            }
+            (Phase::Fighting, GameCommand::UseItem(index)) => self.use_item(index, &mut events),
+            (Phase::Fighting, GameCommand::Run) => self.flee(&mut events),
+            (Phase::Looting, GameCommand::EquipWeapon) => self.collect_loot(true, &mut events),
+            (Phase::Looting, GameCommand::KeepWeapon) => self.collect_loot(false, &mut events),
+            (Phase::Shopping, GameCommand::Buy(index)) => self.buy(index, &mut events),
+            (Phase::Shopping, GameCommand::SellWeapon) => self.sell_weapon(&mut events),
+            (Phase::Shopping, GameCommand::LeaveShop) => self.leave_room(&mut events),
+            (Phase::ChoosingPath, GameCommand::ChooseRoom(column)) => {
+                self.choose_room(column, &mut events);
+            }
+            _ => (),
+        }
+
+        events
+    }
+
+    fn enter_room(&mut self, events: &mut Vec<GameEvent>) {
+        let room = self.dungeon.get_current_room_immutable();
+
+        if room.shop.is_some() {
+            self.phase = Phase::Shopping;
+            events.push(GameEvent::ShopOpened);
+            return;
+        }
+        if room.is_empty() {
+            self.phase = Phase::Looting;
+            events.push(GameEvent::RoomCleared);
+            return;
+        }
+
+        self.phase = Phase::Fighting;
+        self.start_fight();
+        let room = self.dungeon.get_current_room_immutable();
+
+        for index in room.living_monsters() {
+            let monster = &room.monsters[index];
+            let stats = monster.get_stats();
+
+            if monster.is_boss() {
+                events.push(GameEvent::BossAppeared {
+                    name: stats.name,
+                    level: stats.level,
+                });
+            } else {
+                events.push(GameEvent::MonsterAppeared {
+                    name: stats.name,
+                    level: stats.level,
+                });
+            }
+        }
+    }
+
+    /// Plays a round against every living monster, the player striking `target`,
+    /// with a skill if one is given.
+    fn attack(&mut self, target: usize, skill: Option<Skill>, events: &mut Vec<GameEvent>) {
+        let room = self.dungeon.get_current_room_mutable();
+        if !room
+            .monsters
+            .get(target)
+            .is_some_and(|monster| monster.is_alive())
+        {
+            return;
+        }
+        if !self.start_player_turn(events) {
+            self.end_round(events);
+            return;
+        }
+
+        let room = self.dungeon.get_current_room_mutable();
+        let monster = &mut room.monsters[target];
+        // The target may have succumbed to its statuses before the player's turn
+        if !monster.is_alive() {
+            self.end_round(events);
+            return;
+        }
+        if let Some(skill) = skill {
+            self.player.use_skill(skill);
+            events.push(GameEvent::SkillUsed { skill, healed: 0 });
+        }
+        player_strikes(
+            &mut self.player,
+            &mut **monster,
+            skill,
+            &mut self.combat_rng,
+            events,
+        );
+
+        if !monster.is_alive() {
+            self.experience_earned += slay(room, &mut self.timeline, target, events);
+        }
+
+        self.end_player_turn(events);
+        self.end_round(events);
+    }
+
+    fn use_skill(&mut self, skill: Skill, target: usize, events: &mut Vec<GameEvent>) {
+        if !self.player.can_use_skill(skill) {
+            return;
+        }
+        if skill.is_strike() {
+            self.attack(target, Some(skill), events);
+            return;
+        }
+        if !self.start_player_turn(events) {
+            self.end_round(events);
+            return;
+        }
+
+        self.player.use_skill(skill);
+        let healed = self.player.heal(skill.healing(self.player.max_health));
+        events.push(GameEvent::SkillUsed { skill, healed });
+
+        self.end_player_turn(events);
+        self.end_round(events);
+    }
+
+    /// Lets the monsters whose turns come before the player's play them.
+    /// Returns whether the player is still standing and able to take their turn:
+    /// a stunned player loses it straight away.
+    fn start_player_turn(&mut self, events: &mut Vec<GameEvent>) -> bool {
+        if self.timeline.is_empty() {
+            self.start_fight();
+        }
+        self.monsters_act(events);
+
+        if self.player.is_dead() {
+            return false;
+        }
+        if self.player.statuses.has(StatusKind::Stun) {
+            events.push(GameEvent::TurnSkipped {
+                name: self.player.name.clone(),
+            });
+            self.end_player_turn(events);
+            return false;
+        }
+
+        true
+    }
+
+    /// Ticks the player's statuses and schedules their next turn, then plays
+    /// the monsters' turns up to it.
+    fn end_player_turn(&mut self, events: &mut Vec<GameEvent>) {
+        let tick = self.player.statuses.tick();
+        self.player.health -= tick.damage;
+        let healed = self
+            .player
+            .heal(tick.healing + self.player.class.regeneration());
+        report_tick(&self.player.name, tick, healed, events);
+        self.player.recover();
+
+        self.timeline
+            .advance(Combatant::Player, self.player.get_speed());
+        self.monsters_act(events);
+    }
+
+    fn start_fight(&mut self) {
+        let room = self.dungeon.get_current_room_immutable();
+        let monsters = room.living_monsters().map(|index| {
+            (
+                Combatant::Monster(index),
+                room.monsters[index].get_stats().speed,
+            )
+        });
+
+        self.timeline = Timeline::start(
+            std::iter::once((Combatant::Player, self.player.get_speed())).chain(monsters),
+            self.player.strikes_first(),
+        );
+    }
+
+    /// The turns coming up in the current fight, for display.
+    #[must_use]
+    pub fn upcoming_turns(&self, count: usize) -> Vec<Combatant> {
+        let room = self.dungeon.get_current_room_immutable();
+
+        self.timeline.upcoming(
+            |combatant| match combatant {
+                Combatant::Player => self.player.get_speed(),
+                Combatant::Monster(index) => room.monsters[index].get_stats().speed,
+            },
+            count,
+        )
+    }
+
+    /// Settles the round: the player either died or earns the experience of the monsters slain.
+    fn end_round(&mut self, events: &mut Vec<GameEvent>) {
+        if self.player.is_dead() {
+            self.phase = Phase::Over;
+            self.dungeon.handle_player_death();
+            events.push(GameEvent::PlayerDied);
+            return;
+        }
+
+        let experience = std::mem::take(&mut self.experience_earned);
+        if experience > 0 {
+            self.gain_experience(experience, events);
+        }
+        if self.dungeon.get_current_room_immutable().is_empty() {
+            self.end_fight();
+            self.phase = Phase::Looting;
+        }
+    }
+
+    /// Statuses only last for the fight they were inflicted in, and the player
+    /// gets their breath back.
+    fn end_fight(&mut self) {
+        self.player.statuses.clear();
+        self.player.rest();
+        self.timeline = Timeline::default();
+    }
+
+    fn gain_experience(&mut self, experience: u32, events: &mut Vec<GameEvent>) {
+        events.push(GameEvent::ExperienceGained(experience));
+
+        if self.player.gain_experience(experience) > 0 {
+            events.push(GameEvent::LevelUp {
+                level: self.player.level,
+            });
+        }
+    }
+
+    fn use_item(&mut self, index: usize, events: &mut Vec<GameEvent>) {
+        if index >= self.player.inventory.len() {
+            return;
+        }
+        if !self.start_player_turn(events) {
+            self.end_round(events);
+            return;
+        }
+        let Some(item) = self.player.inventory.take(index) else {
+            return;
+        };
+
+        let healed = item.use_on(&mut self.player);
+        events.push(GameEvent::ItemUsed {
+            item: item.to_string(),
+            healed,
+        });
+
+        // Using an item takes the player's turn
+        self.end_player_turn(events);
+        self.end_round(events);
+    }
+
+    /// Plays the monsters' turns until the player's comes up on the timeline.
+    fn monsters_act(&mut self, events: &mut Vec<GameEvent>) {
+        let room = self.dungeon.get_current_room_mutable();
+
+        while let Some(Combatant::Monster(index)) = self.timeline.next() {
+            if self.player.is_dead() {
+                break;
+            }
+            let Some(monster) = room.monsters.get_mut(index) else {
+                self.timeline.remove(Combatant::Monster(index));
+                continue;
+            };
+
+            monster_strikes(
+                &mut **monster,
+                &mut self.player,
+                &mut self.combat_rng,
+                events,
+            );
+
+            let tick = monster.statuses_mut().tick();
+            if tick.damage > 0 {
+                monster.take_damage(tick.damage);
+            }
+            let healed = monster.heal(tick.healing);
+            report_tick(&monster.get_stats().name, tick, healed, events);
+
+            if !monster.is_alive() {
+                self.experience_earned += slay(room, &mut self.timeline, index, events);
+                continue;
+            }
+            self.timeline
+                .advance(Combatant::Monster(index), monster.get_stats().speed);
+        }
+    }
+
+    /// Odds of getting away from the current fight, which depend on how the
+    /// player's speed compares to the quickest monster's. There is no running
+    /// from the last room, as there is nowhere left to run to.
+    #[must_use]
+    pub fn flee_chance(&self) -> Option<f64> {
+        if !self.dungeon.is_there_rooms_left() {
+            return None;
+        }
+        let room = self.dungeon.get_current_room_immutable();
+
+        room.living_monsters()
+            .map(|index| room.monsters[index].get_stats().speed)
+            .max()
+            .map(|fastest| flee_chance(self.player.get_speed(), fastest))
+    }
+
+    /// Tries to get away from the fight. Failing gives the monsters a free round.
+    fn flee(&mut self, events: &mut Vec<GameEvent>) {
+        if !self.dungeon.is_there_rooms_left() {
+            return;
+        }
+        if !self.start_player_turn(events) {
+            self.end_round(events);
+            return;
+        }
+        // Nothing left to run from when the monsters succumbed to their statuses
+        let Some(chance) = self.flee_chance() else {
+            self.end_round(events);
+            return;
+        };
+
+        if !self.combat_rng.random_bool(chance) {
+            events.push(GameEvent::FleeFailed);
+            self.end_player_turn(events);
+            self.end_round(events);
+            return;
+        }
+
+        // The room's treasure is left behind, and some gold is dropped on the way out
+        self.dungeon.get_current_room_mutable().treasures.clear();
+        self.experience_earned = 0;
+        self.end_fight();
+        let gold_lost = self.player.wallet.balance() * FLEE_GOLD_LOSS_PERCENT / 100;
+        let _ = self.player.wallet.withdraw(gold_lost);
+
+        self.leave_room(events);
+        events.push(GameEvent::PlayerEscaped { gold_lost });
+    }
+
+    fn collect_loot(&mut self, equip_weapon: bool, events: &mut Vec<GameEvent>) {
+        let room = self.dungeon.get_current_room_mutable();
+
+        for treasure in &mut room.treasures {
+            if let Some(gold) = treasure.gold.take() {
+                self.player.wallet.deposit(gold);
+                events.push(GameEvent::GoldCollected(gold));
+            }
+            if let Some(potion) = treasure.health_potion.take() {
+                let item = Consumable::HealthPotion(potion);
+                self.player.inventory.add(item.clone());
+                events.push(GameEvent::ItemCollected(item));
+            }
+            // Armor is only worn when it beats the piece already in its slot
+            if let Some(armor) = treasure.armor.take() {
+                if self.player.equipment.is_upgrade(&armor) {
+                    self.player.equipment.equip(armor.clone());
+                    events.push(GameEvent::ArmorEquipped(armor));
+                }
+            }
+        }
+
+        if equip_weapon {
+            if let Some(weapon) = room.treasures.get_weapon() {
+                self.player.equip(weapon.clone());
+                events.push(GameEvent::WeaponEquipped(weapon));
+            }
+        }
+
+        self.leave_room(events);
+    }
+
+    fn buy(&mut self, index: usize, events: &mut Vec<GameEvent>) {
+        let Some(shop) = self.dungeon.get_current_room_mutable().shop.as_mut() else {
+            return;
+        };
+        let Some(price) = shop.stock.get(index).map(ShopItem::price) else {
+            return;
+        };
+
+        match self.player.wallet.pay_for(price, || shop.take(index)) {
+            Ok(Some(item)) => {
+                events.push(GameEvent::ItemBought {
+                    item: item.to_string(),
+                    price,
+                });
+                match item {
+                    ShopItem::Weapon(weapon) => {
+                        self.player.equip(weapon.clone());
+                        events.push(GameEvent::WeaponEquipped(weapon));
+                    }
+                    ShopItem::Armor(armor) => {
+                        self.player.equipment.equip(armor.clone());
+                        events.push(GameEvent::ArmorEquipped(armor));
+                    }
+                    ShopItem::Consumable(item) => self.player.inventory.add(item),
+                }
+            }
+            Ok(None) => (),
+            Err(error) => events.push(GameEvent::PurchaseFailed(error)),
+        }
+    }
+
+    fn choose_room(&mut self, column: usize, events: &mut Vec<GameEvent>) {
+        if self.dungeon.next_room(column) {
+            self.phase = Phase::Exploring;
+            events.push(GameEvent::RoomEntered(self.dungeon.current_room_number));
+        }
+    }
+
+    fn sell_weapon(&mut self, events: &mut Vec<GameEvent>) {
+        let Some(weapon) = self.player.weapon.take() else {
+            return;
+        };
+
+        let price = weapon_sell_price(&weapon);
+        self.player.wallet.deposit(price);
+        events.push(GameEvent::WeaponSold {
+            weapon: weapon.to_string(),
+            price,
+        });
+    }
+
+    /// Opens the map to pick the next room, or completes the zone after its last room.
+    fn leave_room(&mut self, events: &mut Vec<GameEvent>) {
+        if self.dungeon.is_there_rooms_left() {
+            self.phase = Phase::ChoosingPath;
+            events.push(GameEvent::MapOpened);
+        } else {
+            self.phase = Phase::Over;
+            self.dungeon.complete_zone();
+            events.push(GameEvent::ZoneCompleted {
+                zone: self.dungeon.current_zone.name.clone(),
+            });
+        }
+    }
+}
+
+const FLEE_GOLD_LOSS_PERCENT: u32 = 10;
+
+/// Even odds against an equally quick monster, 10% per point of difference.
+fn flee_chance(player_speed: i32, monster_speed: i32) -> f64 {
+    (0.5 + f64::from(player_speed - monster_speed) * 0.1).clamp(0.1, 0.9)
+}
+
+fn player_strikes<R: Rng>(
+    player: &mut Player,
+    monster: &mut dyn Monster,
+    skill: Option<Skill>,
+    rng: &mut R,
+    events: &mut Vec<GameEvent>,
+) {
+    let phase = monster.phase_name();
+    let mut strike = match skill {
+        Some(skill) => player.attack_with(
+            monster,
+            rng,
+            skill.damage_multiplier(),
+            skill.defence_penetration(),
+        ),
+        None => player.attack(monster, rng),
+    };
+    if strike.damage > 0 {
+        strike.inflicted.extend(skill.and_then(Skill::inflicts));
+    }
+
+    events.push(GameEvent::MonsterHit {
+        name: monster.get_stats().name,
+        damage: strike.damage,
+        hits: strike.hits,
+        critical: strike.critical,
+    });
+
+    for status in strike.inflicted {
+        if status.kind.is_beneficial() {
+            inflict(player.name.clone(), &mut player.statuses, status, events);
+        } else if monster.is_alive() {
+            inflict(
+                monster.get_stats().name,
+                monster.statuses_mut(),
+                status,
+                events,
+            );
+        }
+    }
+
+    if monster.is_alive() {
+        if let Some(new_phase) = monster
+            .phase_name()
+            .filter(|new| Some(new) != phase.as_ref())
+        {
+            events.push(GameEvent::BossPhaseChanged {
+                name: monster.get_stats().name,
+                phase: new_phase,
+            });
+        }
+    }
+}
+
+/// The monster's turn, unless it is stunned or the player parries its attack.
+fn monster_strikes<R: Rng>(
+    monster: &mut dyn Monster,
+    player: &mut Player,
+    rng: &mut R,
+    events: &mut Vec<GameEvent>,
+) {
+    if player.is_dead() || !monster.is_alive() {
+        return;
+    }
+    if monster.statuses().has(StatusKind::Stun) {
+        events.push(GameEvent::TurnSkipped {
+            name: monster.get_stats().name,
+        });
+        return;
+    }
+    if player.parries(rng) {
+        events.push(GameEvent::AttackParried {
+            name: monster.get_stats().name,
+        });
+        return;
+    }
+
+    let special_attack = monster.next_special_attack();
+    let hit = damage::resolve(&monster.attack(), &player.defender(), rng);
+    player.health -= hit.damage;
+
+    match special_attack {
+        Some(attack) => events.push(GameEvent::SpecialAttack {
+            name: monster.get_stats().name,
+            attack,
+            hit,
+        }),
+        None => events.push(GameEvent::PlayerHit {
+            name: monster.get_stats().name,
+            hit,
+        }),
+    }
+
+    let Some(status) = monster
+        .on_hit_effect()
+        .filter(|_| hit.landed)
+        .and_then(|effect| effect.roll(rng))
+    else {
+        return;
+    };
+    if status.kind.is_beneficial() {
+        inflict(
+            monster.get_stats().name,
+            monster.statuses_mut(),
+            status,
+            events,
+        );
+    } else if !player.class.resists(status.kind) {
+        inflict(player.name.clone(), &mut player.statuses, status, events);
+    }
+}
+
+fn inflict(
+    name: String,
+    statuses: &mut StatusEffects,
+    status: StatusEffect,
+    events: &mut Vec<GameEvent>,
+) {
+    statuses.apply(status);
+    events.push(GameEvent::StatusApplied { name, status });
+}
+
+fn report_tick(name: &str, tick: StatusTick, healed: i32, events: &mut Vec<GameEvent>) {
+    if tick.damage > 0 || healed > 0 {
+        events.push(GameEvent::StatusTicked {
+            name: name.to_string(),
+            damage: tick.damage,
+            healed,
+        });
+    }
+    for kind in tick.expired {
+        events.push(GameEvent::StatusExpired {
+            name: name.to_string(),
+            kind,
+        });
+    }
+}
+
+/// Removes a fallen monster from the fight and returns the experience it grants.
+fn slay(
+    room: &mut Room,
+    timeline: &mut Timeline,
+    index: usize,
+    events: &mut Vec<GameEvent>,
+) -> u32 {
+    let stats = room.monsters[index].get_stats();
+    let experience = room.monster_slain(index);
+    timeline.remove(Combatant::Monster(index));
+
+    events.push(GameEvent::MonsterSlain {
+        name: stats.name,
+        level: stats.level,
+    });
+    experience
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Time between two turns of a combatant with no speed at all.
+const TURN_LENGTH: u64 = 1000;
+/// Speed needed to act twice as often as a combatant with no speed.
+const SPEED_SCALE: u64 = 10;
+
+/// Ordered so that the player wins ties against monsters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Combatant {
+    Player,
+    /// Index of the monster in the current room.
+    Monster(usize),
+}
+
+/// When each combatant of the fight acts next. Quicker combatants come back
+/// around more often, so a goblin well ahead of the player in speed can strike
+/// twice before the player's next turn.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Timeline {
+    #[serde(with = "turn_list")]
+    turns: BTreeMap<Combatant, u64>,
+}
+
+impl Timeline {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            turns: BTreeMap::new(),
+        }
+    }
+
+    /// Everyone's first turn comes one turn length in, unless the player
+    /// is allowed to strike first.
+    pub fn start<I: IntoIterator<Item = (Combatant, i32)>>(
+        combatants: I,
+        player_first: bool,
+    ) -> Self {
+        let turns = combatants
+            .into_iter()
+            .map(|(combatant, speed)| match combatant {
+                Combatant::Player if player_first => (combatant, 0),
+                _ => (combatant, turn_interval(speed)),
+            })
+            .collect();
+
+        Self { turns }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.turns.is_empty()
+    }
+
+    /// The combatant whose turn comes first.
+    #[must_use]
+    pub fn next(&self) -> Option<Combatant> {
+        self.turns
+            .iter()
+            .min_by_key(|(combatant, time)| (**time, **combatant))
+            .map(|(combatant, _)| *combatant)
+    }
+
+    /// Schedules the next turn of a combatant that just acted.
+    pub fn advance(&mut self, combatant: Combatant, speed: i32) {
+        if let Some(time) = self.turns.get_mut(&combatant) {
+            *time += turn_interval(speed);
+        }
+    }
+
+    pub fn remove(&mut self, combatant: Combatant) {
+        self.turns.remove(&combatant);
+    }
+
+    /// The next `count` turns, assuming everyone keeps their current speed.
+    pub fn upcoming<F: Fn(Combatant) -> i32>(&self, speed_of: F, count: usize) -> Vec<Combatant> {
+        let mut timeline = self.clone();
+        let mut upcoming = Vec::with_capacity(count);
+
+        while upcoming.len() < count {
+            let Some(combatant) = timeline.next() else {
+                break;
+            };
+            upcoming.push(combatant);
+            timeline.advance(combatant, speed_of(combatant));
+        }
+
+        upcoming
+    }
+}
+
+fn turn_interval(speed: i32) -> u64 {
+    TURN_LENGTH * SPEED_SCALE / (u64::from(speed.max(0).unsigned_abs()) + SPEED_SCALE)
+}
+
+/// Saves write the turns as a list, as JSON only has string keys.
+mod turn_list {
+    use super::{BTreeMap, Combatant, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        turns: &BTreeMap<Combatant, u64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(turns)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BTreeMap<Combatant, u64>, D::Error> {
+        Vec::<(Combatant, u64)>::deserialize(deserializer).map(|turns| turns.into_iter().collect())
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{entity::Player, room::HealthPotion};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Consumable {
+    HealthPotion(HealthPotion),
+}
+
+impl Consumable {
+    /// Applies the item to the player and returns the health restored.
+    pub fn use_on(&self, player: &mut Player) -> i32 {
+        match self {
+            Self::HealthPotion(potion) => player.heal(potion.heal_amount()),
+        }
+    }
+}
+
+impl Display for Consumable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HealthPotion(potion) => write!(f, "{potion}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Inventory {
+    items: Vec<Consumable>,
+}
+
+impl Inventory {
+    pub fn add(&mut self, item: Consumable) {
+        self.items.push(item);
+    }
+
+    /// Removes the item at `index` so it can be used.
+    pub fn take(&mut self, index: usize) -> Option<Consumable> {
+        (index < self.items.len()).then(|| self.items.remove(index))
+    }
+
+    #[must_use]
+    pub fn items(&self) -> &[Consumable] {
+        &self.items
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
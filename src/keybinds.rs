@@ -1,12 +1,25 @@
+use std::io::{self, ErrorKind};
+
+use color_eyre::Report;
 use ratatui::crossterm::event::{KeyCode, KeyEvent};
 
 use crate::{
     app::App,
-    room::WeaponUtils,
+    class::CharacterClass,
+    game::{GameCommand, GameEvent},
+    save::SAVE_FILE_PATH,
     screen::Screen,
-    utils::{ChangeWeaponOption, CombatOption, MainMenuOption},
+    skills::Skill,
+    utils::{
+        ChangeWeaponOption, ClassOption, CombatOption, ItemOption, MainMenuOption, MapOption,
+        ShopOption, SkillOption, TargetOption, ZoneOption,
+    },
+    zones::zone::{Zone, ZoneDifficulty},
 };
 
+/// Longest name a hero can be given.
+const MAX_NAME_LENGTH: usize = 16;
+
 impl App {
     pub fn handle_main_screen(&mut self, key: KeyEvent) -> bool {
         #[allow(clippy::match_same_arms)]
@@ -14,12 +27,14 @@ impl App {
             KeyCode::Up => self.option_up(),
             KeyCode::Down => self.option_down(),
             KeyCode::Enter => match self.current_main_menu_option {
-                MainMenuOption::NewGame => {
-                    self.create_player();
-                    self.create_dungeon();
-                    self.switch_screen(Screen::DungeonLoading);
+                MainMenuOption::NewGame => self.switch_screen(Screen::NameEntry),
+                MainMenuOption::LoadGame => {
+                    // Nothing to resume when the save is missing or unreadable
+                    if let Err(error) = self.load_game(SAVE_FILE_PATH) {
+                        self.notice = Some(load_failure(&error));
+                        self.current_main_menu_option = MainMenuOption::NewGame;
+                    }
                 }
-                MainMenuOption::LoadGame => return true,
                 MainMenuOption::Quit => return true,
             },
             _ => (),
@@ -27,75 +42,304 @@ impl App {
         false
     }
 
+    pub fn handle_name_entry(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(c) if self.hero_name.chars().count() < MAX_NAME_LENGTH => {
+                self.hero_name.push(c);
+            }
+            KeyCode::Backspace if self.hero_name.is_empty() => {
+                self.switch_screen(Screen::MainMenu);
+            }
+            KeyCode::Backspace => {
+                self.hero_name.pop();
+            }
+            KeyCode::Enter if !self.hero_name.trim().is_empty() => {
+                self.current_class_option = ClassOption::default();
+                self.switch_screen(Screen::ClassSelection);
+            }
+            _ => (),
+        }
+    }
+
+    pub fn handle_class_selection(&mut self, key: KeyEvent) {
+        let ClassOption(index) = self.current_class_option;
+
+        match key.code {
+            KeyCode::Up => self.current_class_option = ClassOption(index.saturating_sub(1)),
+            KeyCode::Down if index + 1 < CharacterClass::ALL.len() => {
+                self.current_class_option = ClassOption(index + 1);
+            }
+            KeyCode::Enter => {
+                self.current_zone_option = ZoneOption::default();
+                self.switch_screen(Screen::ZoneSelection);
+            }
+            KeyCode::Backspace => self.switch_screen(Screen::NameEntry),
+            _ => (),
+        }
+    }
+
+    pub fn handle_zone_selection(&mut self, key: KeyEvent) {
+        let zones = Zone::get_available_zones(&self.current_zone_difficulty);
+        let ZoneOption(index) = self.current_zone_option;
+
+        match key.code {
+            KeyCode::Left => self.select_zone_difficulty(self.current_zone_difficulty.easier()),
+            KeyCode::Right => self.select_zone_difficulty(self.current_zone_difficulty.harder()),
+            KeyCode::Up => self.current_zone_option = ZoneOption(index.saturating_sub(1)),
+            KeyCode::Down if index + 1 < zones.len() => {
+                self.current_zone_option = ZoneOption(index + 1);
+            }
+            KeyCode::Enter => {
+                if let Some(zone) = zones.get(index) {
+                    if self.campaign.is_unlocked(zone) {
+                        self.start_new_game(zone.clone());
+                        self.switch_screen(Screen::DungeonLoading);
+                    }
+                }
+            }
+            KeyCode::Backspace => self.switch_screen(Screen::ClassSelection),
+            _ => (),
+        }
+    }
+
+    fn select_zone_difficulty(&mut self, difficulty: ZoneDifficulty) {
+        let zone_count = Zone::get_available_zones(&difficulty).len();
+        let ZoneOption(index) = self.current_zone_option;
+
+        self.current_zone_option = ZoneOption(index.min(zone_count.saturating_sub(1)));
+        self.current_zone_difficulty = difficulty;
+    }
+
     pub fn handle_room(&mut self, key: KeyEvent) {
-        if key.code == KeyCode::Enter && !self.dungeon.current_room.monsters.is_empty() {
-            self.switch_screen(Screen::CombatLoading);
+        if key.code == KeyCode::Enter {
+            self.send(GameCommand::EnterRoom);
         }
     }
 
     pub fn handle_combat(&mut self, key: KeyEvent) {
-        let room = self.dungeon.get_current_room_mutable();
-        let monsters = &mut room.monsters;
-        let monster = &mut monsters[room.current_monster];
-        let player = &mut self.player;
-
         match key.code {
             KeyCode::Enter => match self.current_combat_option {
                 CombatOption::Attack => {
-                    if player.speed > monster.get_stats().speed {
-                        player.attack(&mut **monster);
-                        if monster.is_alive() {
-                            monster.attack(player);
-                        }
-                    } else {
-                        if monster.is_alive() {
-                            monster.attack(player);
-                        }
-                        player.attack(&mut **monster);
-                    }
-
-                    if !monster.is_alive() {
-                        room.monster_slain();
-                        self.switch_screen(Screen::DefeatMonster);
-                    } else if player.is_dead() {
-                        self.switch_screen(Screen::DeadPlayer);
-                    }
+                    let TargetOption(target) = self.current_target_option;
+                    self.send(GameCommand::Attack(target));
+                }
+                CombatOption::Skills => {
+                    self.current_skill_option = SkillOption::default();
+                    self.switch_screen(Screen::SkillPicker);
+                }
+                CombatOption::UseItem => {
+                    self.current_item_option = ItemOption::default();
+                    self.switch_screen(Screen::ItemPicker);
                 }
-                CombatOption::Run => self.switch_screen(Screen::RunScreen),
+                CombatOption::Run => self.send(GameCommand::Run),
             },
             KeyCode::Up => self.option_up(),
             KeyCode::Down => self.option_down(),
+            KeyCode::Left => self.cycle_target(false),
+            KeyCode::Right => self.cycle_target(true),
+            KeyCode::PageUp => self.combat_log.scroll_up(),
+            KeyCode::PageDown => self.combat_log.scroll_down(),
+            _ => (),
+        }
+    }
+
+    /// Moves the target to the next living monster, wrapping around the room.
+    fn cycle_target(&mut self, forward: bool) {
+        let room = self.game.dungeon.get_current_room_immutable();
+        let living: Vec<usize> = room.living_monsters().collect();
+        let TargetOption(target) = self.current_target_option;
+
+        let next = if forward {
+            living
+                .iter()
+                .find(|&&index| index > target)
+                .or(living.first())
+        } else {
+            living
+                .iter()
+                .rev()
+                .find(|&&index| index < target)
+                .or(living.last())
+        };
+
+        if let Some(&index) = next {
+            self.current_target_option = TargetOption(index);
+        }
+    }
+
+    /// Aims at the first monster still standing.
+    pub fn reset_target(&mut self) {
+        let room = self.game.dungeon.get_current_room_immutable();
+        self.current_target_option = TargetOption(room.living_monsters().next().unwrap_or(0));
+    }
+
+    pub fn handle_item_picker(&mut self, key: KeyEvent) {
+        let item_count = self.game.player.inventory.len();
+        let ItemOption(index) = self.current_item_option;
+
+        match key.code {
+            KeyCode::Up => self.current_item_option = ItemOption(index.saturating_sub(1)),
+            KeyCode::Down if index + 1 < item_count => {
+                self.current_item_option = ItemOption(index + 1);
+            }
+            KeyCode::Enter if index < item_count => self.send(GameCommand::UseItem(index)),
+            KeyCode::Backspace => self.switch_screen(Screen::Combat),
+            _ => (),
+        }
+    }
+
+    pub fn handle_skill_picker(&mut self, key: KeyEvent) {
+        let SkillOption(index) = self.current_skill_option;
+        let TargetOption(target) = self.current_target_option;
+
+        match key.code {
+            KeyCode::Up => self.current_skill_option = SkillOption(index.saturating_sub(1)),
+            KeyCode::Down if index + 1 < Skill::ALL.len() => {
+                self.current_skill_option = SkillOption(index + 1);
+            }
+            KeyCode::Enter if self.game.player.can_use_skill(Skill::ALL[index]) => {
+                self.send(GameCommand::UseSkill {
+                    skill: Skill::ALL[index],
+                    target,
+                });
+            }
+            KeyCode::Backspace => self.switch_screen(Screen::Combat),
             _ => (),
         }
     }
 
-    #[allow(clippy::missing_panics_doc)]
     pub fn handle_change_weapon(&mut self, key: KeyEvent) {
-        let room = self.dungeon.get_current_room_mutable();
-        let player = &mut self.player;
-        if room.treasures.contains_weapon() {
-            let weapon = room.treasures.get_weapon().expect("Should not be empty");
-            match key.code {
-                KeyCode::Enter => {
-                    match self.current_change_weapon_option {
-                        ChangeWeaponOption::Yes => player.equip(weapon),
-                        ChangeWeaponOption::No => (),
-                    }
-                    if room.is_empty() {
-                        let dungeon = &mut self.dungeon;
-                        if dungeon.is_there_rooms_left() {
-                            dungeon.next_room();
-                            self.switch_screen(Screen::RoomLoading);
-                        } else {
-                            self.switch_screen(Screen::MainMenu);
-                        }
+        match key.code {
+            KeyCode::Enter => match self.current_change_weapon_option {
+                ChangeWeaponOption::Yes => self.send(GameCommand::EquipWeapon),
+                ChangeWeaponOption::No => self.send(GameCommand::KeepWeapon),
+            },
+            KeyCode::Up => self.option_up(),
+            KeyCode::Down => self.option_down(),
+            _ => (),
+        }
+    }
+
+    pub fn handle_shop(&mut self, key: KeyEvent) {
+        let stock_len = self
+            .game
+            .dungeon
+            .get_current_room_immutable()
+            .shop
+            .as_ref()
+            .map_or(0, |shop| shop.stock.len());
+        let ShopOption(index) = self.current_shop_option;
+
+        match key.code {
+            KeyCode::Up => self.current_shop_option = ShopOption(index.saturating_sub(1)),
+            // The stock is followed by "Sell weapon" and "Leave"
+            KeyCode::Down if index < stock_len + 1 => {
+                self.current_shop_option = ShopOption(index + 1);
+            }
+            KeyCode::Enter if index < stock_len => self.send(GameCommand::Buy(index)),
+            KeyCode::Enter if index == stock_len => self.send(GameCommand::SellWeapon),
+            KeyCode::Enter => self.send(GameCommand::LeaveShop),
+            _ => (),
+        }
+    }
+
+    pub fn handle_map(&mut self, key: KeyEvent) {
+        let next_rooms = self.game.dungeon.next_rooms();
+        let MapOption(index) = self.current_map_option;
+
+        match key.code {
+            KeyCode::Left => self.current_map_option = MapOption(index.saturating_sub(1)),
+            KeyCode::Right if index + 1 < next_rooms.len() => {
+                self.current_map_option = MapOption(index + 1);
+            }
+            KeyCode::Enter => {
+                if let Some(column) = next_rooms.get(index).map(|node| node.column) {
+                    self.send(GameCommand::ChooseRoom(column));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Forwards a command to the game and moves to the screen matching its outcome.
+    fn send(&mut self, command: GameCommand) {
+        for event in self.game.apply(command) {
+            self.combat_log.record(&event);
+
+            match event {
+                GameEvent::MonsterAppeared { .. } => {
+                    self.reset_target();
+                    self.switch_screen(Screen::CombatLoading);
+                }
+                // The fight goes on until the whole group is down
+                GameEvent::MonsterSlain { .. } => {
+                    if self.game.dungeon.get_current_room_immutable().is_empty() {
+                        self.switch_screen(Screen::DefeatMonster);
                     } else {
-                        self.switch_screen(Screen::CombatLoading);
+                        self.reset_target();
                     }
                 }
-                KeyCode::Up => self.option_up(),
-                KeyCode::Down => self.option_down(),
-                _ => (),
+                GameEvent::RoomCleared => self.switch_screen(Screen::RoomResult),
+                // The log only covers the fight of the current room
+                GameEvent::RoomEntered(_) => {
+                    self.combat_log.clear();
+                    self.switch_screen(Screen::RoomLoading);
+                }
+                GameEvent::MapOpened => {
+                    self.current_map_option = MapOption::default();
+                    self.switch_screen(Screen::Map);
+                }
+                GameEvent::BossAppeared { .. } => {
+                    self.reset_target();
+                    self.switch_screen(Screen::BossIntro);
+                }
+                GameEvent::ZoneCompleted { .. } => {
+                    self.campaign
+                        .mark_cleared(&self.game.dungeon.current_zone.id);
+                    self.switch_screen(Screen::ZoneCleared);
+                }
+                GameEvent::LevelUp { .. } => {
+                    if self.game.dungeon.get_current_room_immutable().is_empty() {
+                        self.switch_screen(Screen::LevelUp);
+                    }
+                }
+                // A stunned hero loses the turn without using what was picked
+                GameEvent::ItemUsed { .. }
+                | GameEvent::SkillUsed { .. }
+                | GameEvent::TurnSkipped { .. } => {
+                    self.switch_screen(Screen::Combat);
+                }
+                GameEvent::PlayerDied => self.switch_screen(Screen::DeadPlayer),
+                GameEvent::PlayerEscaped { gold_lost } => {
+                    self.switch_screen(Screen::Escaped { gold_lost });
+                }
+                GameEvent::ShopOpened => {
+                    self.current_shop_option = ShopOption::default();
+                    self.shop_message = None;
+                    self.switch_screen(Screen::Shop);
+                }
+                GameEvent::ItemBought { item, price } => {
+                    self.current_shop_option = ShopOption::default();
+                    self.shop_message = Some(format!("Bought {item} for {price} gold."));
+                }
+                GameEvent::WeaponSold { weapon, price } => {
+                    self.shop_message = Some(format!("Sold {weapon} for {price} gold."));
+                }
+                GameEvent::PurchaseFailed(error) => self.shop_message = Some(error.to_string()),
+                GameEvent::PlayerHit { .. }
+                | GameEvent::SpecialAttack { .. }
+                | GameEvent::MonsterHit { .. }
+                | GameEvent::StatusApplied { .. }
+                | GameEvent::StatusTicked { .. }
+                | GameEvent::StatusExpired { .. }
+                | GameEvent::AttackParried { .. }
+                | GameEvent::FleeFailed
+                | GameEvent::BossPhaseChanged { .. }
+                | GameEvent::ExperienceGained(_)
+                | GameEvent::ItemCollected(_)
+                | GameEvent::GoldCollected(_)
+                | GameEvent::WeaponEquipped(_)
+                | GameEvent::ArmorEquipped(_) => (),
             }
         }
     }
@@ -106,7 +350,11 @@ impl App {
                 MainMenuOption::NewGame => self.current_main_menu_option = MainMenuOption::LoadGame,
                 _ => self.current_main_menu_option = MainMenuOption::Quit,
             },
-            Screen::Combat => self.current_combat_option = CombatOption::Run,
+            Screen::Combat => match self.current_combat_option {
+                CombatOption::Attack => self.current_combat_option = CombatOption::Skills,
+                CombatOption::Skills => self.current_combat_option = CombatOption::UseItem,
+                _ => self.current_combat_option = CombatOption::Run,
+            },
             Screen::RoomResult => self.current_change_weapon_option = ChangeWeaponOption::No,
             _ => (),
         }
@@ -118,9 +366,26 @@ impl App {
                 MainMenuOption::Quit => self.current_main_menu_option = MainMenuOption::LoadGame,
                 _ => self.current_main_menu_option = MainMenuOption::NewGame,
             },
-            Screen::Combat => self.current_combat_option = CombatOption::Attack,
+            Screen::Combat => match self.current_combat_option {
+                CombatOption::Run => self.current_combat_option = CombatOption::UseItem,
+                CombatOption::UseItem => self.current_combat_option = CombatOption::Skills,
+                _ => self.current_combat_option = CombatOption::Attack,
+            },
             Screen::RoomResult => self.current_change_weapon_option = ChangeWeaponOption::Yes,
             _ => (),
         }
     }
 }
+
+/// Tells the player why the saved run could not be resumed.
+fn load_failure(error: &Report) -> String {
+    let is_missing = error
+        .downcast_ref::<io::Error>()
+        .is_some_and(|error| error.kind() == ErrorKind::NotFound);
+
+    if is_missing {
+        "There is no saved run to load.".to_string()
+    } else {
+        format!("Could not load the saved run: {error}")
+    }
+}
@@ -1,9 +1,24 @@
 pub mod app;
+pub mod campaign;
+pub mod class;
+pub mod combat_log;
+pub mod damage;
+pub mod data;
 pub mod dungeon;
+pub mod economy;
+pub mod equipment;
 pub mod entity;
+pub mod game;
+pub mod initiative;
+pub mod inventory;
 pub mod keybinds;
+pub mod loot;
 pub mod monsters;
 pub mod room;
+pub mod save;
 pub mod screen;
+pub mod shop;
+pub mod skills;
+pub mod status;
 pub mod utils;
 pub mod zones;
\ No newline at end of file
@@ -0,0 +1,296 @@
+use std::ops::RangeInclusive;
+
+use rand::{
+    distr::weighted::WeightedIndex, prelude::Distribution, seq::IndexedRandom, seq::SliceRandom,
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entity::{Rarity, Weapon, WeaponType},
+    equipment::{Armor, ArmorSlot},
+    room::RoomType,
+    status::{OnHitEffect, StatusKind},
+    zones::zone::ZoneDifficulty,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AffixPosition {
+    Prefix,
+    Suffix,
+}
+
+/// A named modifier rolled on a weapon, such as "Venomous" or "of Haste".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Affix {
+    pub name: String,
+    pub position: AffixPosition,
+    /// Already included in the weapon's `attack_value`.
+    pub attack: i32,
+    pub defence: i32,
+    pub speed: i32,
+    /// Status a hit may inflict on top of the weapon type's own.
+    #[serde(default)]
+    pub effect: Option<OnHitEffect>,
+}
+
+struct AffixTemplate {
+    name: &'static str,
+    position: AffixPosition,
+    attack: i32,
+    defence: i32,
+    speed: i32,
+    effect: Option<OnHitEffect>,
+}
+
+static AFFIXES: [AffixTemplate; 10] = [
+    AffixTemplate {
+        name: "Sharp",
+        position: AffixPosition::Prefix,
+        attack: 2,
+        defence: 0,
+        speed: 0,
+        effect: None,
+    },
+    AffixTemplate {
+        name: "Venomous",
+        position: AffixPosition::Prefix,
+        attack: 1,
+        defence: 0,
+        speed: 0,
+        effect: Some(OnHitEffect {
+            kind: StatusKind::Poison,
+            chance: 0.3,
+            potency: 2,
+            turns: 3,
+        }),
+    },
+    AffixTemplate {
+        name: "Sturdy",
+        position: AffixPosition::Prefix,
+        attack: 0,
+        defence: 1,
+        speed: 0,
+        effect: None,
+    },
+    AffixTemplate {
+        name: "Swift",
+        position: AffixPosition::Prefix,
+        attack: 0,
+        defence: 0,
+        speed: 1,
+        effect: None,
+    },
+    AffixTemplate {
+        name: "Brutal",
+        position: AffixPosition::Prefix,
+        attack: 4,
+        defence: 0,
+        speed: -1,
+        effect: None,
+    },
+    AffixTemplate {
+        name: "of Haste",
+        position: AffixPosition::Suffix,
+        attack: 0,
+        defence: 0,
+        speed: 2,
+        effect: None,
+    },
+    AffixTemplate {
+        name: "of the Bear",
+        position: AffixPosition::Suffix,
+        attack: 0,
+        defence: 2,
+        speed: 0,
+        effect: None,
+    },
+    AffixTemplate {
+        name: "of Might",
+        position: AffixPosition::Suffix,
+        attack: 3,
+        defence: 0,
+        speed: 0,
+        effect: None,
+    },
+    AffixTemplate {
+        name: "of the Fox",
+        position: AffixPosition::Suffix,
+        attack: 1,
+        defence: 0,
+        speed: 1,
+        effect: None,
+    },
+    AffixTemplate {
+        name: "of the Troll",
+        position: AffixPosition::Suffix,
+        attack: 0,
+        defence: 0,
+        speed: 0,
+        effect: Some(OnHitEffect {
+            kind: StatusKind::Regeneration,
+            chance: 0.25,
+            potency: 1,
+            turns: 3,
+        }),
+    },
+];
+
+impl AffixTemplate {
+    fn roll(&self, power: i32) -> Affix {
+        Affix {
+            name: self.name.to_string(),
+            position: self.position,
+            attack: self.attack * power,
+            defence: self.defence * power,
+            speed: self.speed * power,
+            effect: self.effect.map(|effect| OnHitEffect {
+                potency: effect.potency * power,
+                ..effect
+            }),
+        }
+    }
+}
+
+impl Rarity {
+    pub const ALL: [Self; 5] = [
+        Self::Common,
+        Self::Rare,
+        Self::Epic,
+        Self::Legendary,
+        Self::Mythical,
+    ];
+
+    /// Attack added on top of the weapon type's base attack.
+    #[must_use]
+    pub const fn attack_bonus_range(&self) -> RangeInclusive<i32> {
+        match self {
+            Self::Common => -2..=1,
+            Self::Rare => 1..=3,
+            Self::Epic => 3..=6,
+            Self::Legendary => 6..=9,
+            Self::Mythical => 9..=13,
+        }
+    }
+
+    /// How many affixes a weapon of this rarity rolls, one prefix and one suffix at most.
+    #[must_use]
+    pub const fn affix_count(&self) -> usize {
+        match self {
+            Self::Common => 0,
+            Self::Rare => 1,
+            Self::Epic | Self::Legendary | Self::Mythical => 2,
+        }
+    }
+
+    /// Defence added on top of the armor slot's base defence.
+    #[must_use]
+    pub const fn armor_bonus(&self) -> i32 {
+        match self {
+            Self::Common => 0,
+            Self::Rare => 1,
+            Self::Epic => 2,
+            Self::Legendary => 3,
+            Self::Mythical => 5,
+        }
+    }
+
+    /// Multiplier applied to the stats of the rolled affixes.
+    #[must_use]
+    pub const fn affix_power(&self) -> i32 {
+        match self {
+            Self::Common | Self::Rare | Self::Epic => 1,
+            Self::Legendary => 2,
+            Self::Mythical => 3,
+        }
+    }
+}
+
+/// Base odds of each rarity, from Common to Mythical.
+const fn rarity_weights(difficulty: &ZoneDifficulty) -> [f64; 5] {
+    match difficulty {
+        ZoneDifficulty::Normal => [80.0, 17.0, 3.0, 0.0, 0.0],
+        ZoneDifficulty::Hard => [50.0, 33.0, 14.0, 3.0, 0.0],
+        ZoneDifficulty::Nightmare => [10.0, 35.0, 35.0, 17.0, 3.0],
+    }
+}
+
+/// Rooms that are harder to clear make the upper tiers more likely.
+const fn room_luck(room_type: &RoomType) -> f64 {
+    match room_type {
+        RoomType::Entrance | RoomType::Normal | RoomType::Merchant => 0.0,
+        RoomType::Elite => 0.5,
+        RoomType::Treasure => 0.75,
+        RoomType::Boss => 1.5,
+    }
+}
+
+/// Rolls a rarity from the zone's weighted table, skewed by the room type.
+pub fn roll_rarity<R: Rng>(
+    difficulty: &ZoneDifficulty,
+    room_type: &RoomType,
+    rng: &mut R,
+) -> Rarity {
+    let luck = room_luck(room_type);
+    let mut weights = rarity_weights(difficulty);
+
+    #[allow(clippy::cast_precision_loss)]
+    for (tier, weight) in weights.iter_mut().enumerate() {
+        *weight *= luck.mul_add(tier as f64, 1.0);
+    }
+
+    WeightedIndex::new(weights)
+        .map(|distribution| Rarity::ALL[distribution.sample(rng)].clone())
+        .unwrap_or(Rarity::Common)
+}
+
+pub fn generate_weapon<R: Rng>(weapon_type: WeaponType, rarity: Rarity, rng: &mut R) -> Weapon {
+    let mut attack_value =
+        (weapon_type.base_attack() + rng.random_range(rarity.attack_bonus_range())).max(1);
+
+    // A single affix is as likely to be a prefix as a suffix
+    let mut positions = [AffixPosition::Prefix, AffixPosition::Suffix];
+    positions.shuffle(rng);
+
+    let mut affixes = Vec::with_capacity(rarity.affix_count());
+    for position in positions.into_iter().take(rarity.affix_count()) {
+        let candidates: Vec<&AffixTemplate> = AFFIXES
+            .iter()
+            .filter(|affix| affix.position == position)
+            .collect();
+
+        if let Some(template) = candidates.choose(rng) {
+            let affix = template.roll(rarity.affix_power());
+            attack_value += affix.attack;
+            affixes.push(affix);
+        }
+    }
+
+    Weapon::new(weapon_type, rarity, attack_value, affixes)
+}
+
+pub fn generate_random_weapon<R: Rng>(rarity: Rarity, rng: &mut R) -> Weapon {
+    let weapon_type = WeaponType::ALL
+        .choose(rng)
+        .cloned()
+        .unwrap_or(WeaponType::Sword);
+
+    generate_weapon(weapon_type, rarity, rng)
+}
+
+pub fn generate_armor<R: Rng>(slot: ArmorSlot, rarity: Rarity, rng: &mut R) -> Armor {
+    let defence = slot.base_defence() + rarity.armor_bonus() + rng.random_range(0..=1);
+    // Finer pieces are also lighter
+    let speed = slot.base_speed() + i32::from(rarity.armor_bonus() >= 2 && rng.random_bool(0.5));
+
+    Armor::new(slot, rarity, defence, speed)
+}
+
+pub fn generate_random_armor<R: Rng>(rarity: Rarity, rng: &mut R) -> Armor {
+    let slot = ArmorSlot::ALL
+        .choose(rng)
+        .copied()
+        .unwrap_or(ArmorSlot::Body);
+
+    generate_armor(slot, rarity, rng)
+}
@@ -1,14 +1,36 @@
-use color_eyre::{eyre::Ok, Result};
-use ardentia::app::App;
+use color_eyre::Result;
+use ardentia::{
+    app::App, campaign::Campaign, monsters::registry::MonsterRegistry,
+    zones::catalogue::ZoneCatalogue,
+};
 
 fn main() -> Result<()> {
     color_eyre::install()?;
+    // Broken data or progress files are reported before the terminal is taken over
+    MonsterRegistry::init()?;
+    ZoneCatalogue::init()?;
+    let campaign = Campaign::load()?;
+
     let terminal = ratatui::init();
-    let mut app = App::default();
+    let mut app = App {
+        seed: seed_from_args(),
+        campaign,
+        ..App::default()
+    };
 
-    app.run(terminal)?;
+    let result = app.run(terminal);
 
     ratatui::restore();
 
-    Ok(())
+    result
+}
+
+/// Reads an optional `--seed <number>` argument to replay a specific run.
+fn seed_from_args() -> Option<u64> {
+    let args: Vec<String> = std::env::args().collect();
+
+    args.iter()
+        .position(|arg| arg == "--seed")
+        .and_then(|index| args.get(index + 1))
+        .and_then(|seed| seed.parse().ok())
 }
@@ -1,9 +1,9 @@
-use rand::seq::IndexedRandom;
+use rand::{seq::IndexedRandom, Rng};
 
 use crate::{
-    monsters::{create_monster, Monster},
+    monsters::{create_monster, registry::MonsterRegistry, Monster},
     room::RoomType,
-    zones::zone::{Zone},
+    zones::zone::Zone,
 };
 
 #[derive(Debug, Clone)]
@@ -23,15 +23,16 @@ impl MonsterBalancer {
     }
 
     #[must_use]
-    pub fn generate_monsters_for_room(
+    pub fn generate_monsters_for_room<R: Rng>(
         &self,
         room_data: &RoomData,
         room_size: usize,
+        rng: &mut R,
     ) -> Vec<Box<dyn Monster>> {
         let mut monsters = Vec::new();
 
         for _ in 0..room_size {
-            if let Some(monster) = generate_monster_for_room(room_data) {
+            if let Some(monster) = generate_monster_for_room(room_data, rng) {
                 monsters.push(monster);
             }
         }
@@ -40,51 +41,46 @@ impl MonsterBalancer {
     }
 }
 
-fn generate_monster_for_room(room_data: &RoomData) -> Option<Box<dyn Monster>> {
-    let mut rng = rand::rng();
-
+fn generate_monster_for_room<R: Rng>(
+    room_data: &RoomData,
+    rng: &mut R,
+) -> Option<Box<dyn Monster>> {
     // Salle de boss : génère le boss spécifique à la zone
-    // if room_data.room_type == RoomType::Boss {
-    //     return self.generate_boss(&room_data.zone);
-    // }
+    if room_data.room_type == RoomType::Boss {
+        if let Some(boss) = generate_boss(&room_data.zone) {
+            return Some(boss);
+        }
+    }
 
     // Choix entre monstres génériques et spécifiques à la zone
     let monster_pool = get_monster_pool(room_data);
-    let monster_name = monster_pool.choose(&mut rng)?;
+    let monster_name = monster_pool.choose(rng)?;
 
     let monster_level = calculate_monster_level(room_data);
     create_monster(monster_name, monster_level)
 }
 
-// fn generate_boss(zone: &Zone) -> Option<Box<dyn Monster>> {
-//     let boss_level = calculate_boss_level(zone);
+fn generate_boss(zone: &Zone) -> Option<Box<dyn Monster>> {
+    let boss_level = calculate_boss_level(zone);
 
-//     match zone.zone_type {
-//         ZoneType::Jungle => {
-//             Some(Box::new(JungleBoss::new(boss_level, &zone.boss_name)))
-//         }
-//     }
-// }
+    create_monster(&zone.boss_name, boss_level)
+}
 
 fn get_monster_pool(room_data: &RoomData) -> Vec<String> {
-    let mut pool = Vec::new();
+    let mut pool = room_data.zone.unique_monsters.clone();
 
-    pool.extend_from_slice(&[
-        "Slime".to_string(),
-        "Goblin".to_string(),
-        "Ogre".to_string(),
-    ]);
-
-    for monster_name in &room_data.zone.unique_monsters {
-        pool.push(monster_name.clone());
+    if pool.is_empty() {
+        pool = MonsterRegistry::global().names_with_tag("common");
     }
-    
-    pool = match room_data.room_number {
-        1 => vec!["Slime".to_string()],
-        2..3 => vec!["Slime".to_string(), "Goblin".to_string()],
-        _ => pool
+
+    // Les premières salles n'utilisent que les monstres les plus faibles de la zone
+    let weakest = match room_data.room_number {
+        1 => 1,
+        2..3 => 2,
+        _ => pool.len(),
     };
-    
+    pool.truncate(weakest);
+
     pool
 }
 
@@ -94,7 +90,7 @@ fn calculate_monster_level(room_data: &RoomData) -> i32 {
 
     let room_bonus = match room_data.room_type {
         RoomType::Entrance => -1,
-        RoomType::Normal => 0,
+        RoomType::Normal | RoomType::Merchant => 0,
         RoomType::Elite => 2,
         RoomType::Treasure => 1,
         RoomType::Boss => 5,
@@ -103,7 +99,7 @@ fn calculate_monster_level(room_data: &RoomData) -> i32 {
     (base_level + room_bonus).clamp(min_level, max_level)
 }
 
-// fn calculate_boss_level(zone: &Zone) -> i32 {
-//     let (_, max_level) = zone.get_monster_level_range();
-//     max_level + 2
-// }
\ No newline at end of file
+fn calculate_boss_level(zone: &Zone) -> i32 {
+    let (_, max_level) = zone.get_monster_level_range();
+    max_level + 2
+}
@@ -0,0 +1,163 @@
+use crate::{
+    damage::Attack,
+    monsters::{
+        definition::{BossPhase, DefinedMonster, MonsterDefinition, SpecialAttack},
+        Monster, MonsterSave, MonsterStats,
+    },
+    status::{OnHitEffect, StatusEffects},
+};
+
+/// A zone boss, growing stronger as its health crosses the thresholds of its phases.
+#[derive(Debug)]
+pub struct Boss {
+    monster: DefinedMonster,
+    phases: Vec<BossPhase>,
+    phase_index: usize,
+    phase_turns: u32,
+}
+
+impl Boss {
+    #[must_use]
+    pub fn new(definition: &MonsterDefinition, level: i32) -> Self {
+        Self::from_monster(definition, DefinedMonster::new(definition, level))
+    }
+
+    /// Rebuilds a saved boss. Its phase follows from the saved health, and
+    /// the other stats from the definition, so that older saves holding the
+    /// bonuses of the phase load correctly too.
+    #[must_use]
+    pub fn from_save(definition: &MonsterDefinition, save: MonsterSave) -> Self {
+        let stats = MonsterStats {
+            health: save.stats.health,
+            ..definition.stats_at_level(save.stats.level)
+        };
+        let phase_turns = save.phase_turns;
+        let mut boss = Self::from_monster(
+            definition,
+            DefinedMonster::from_save(definition, MonsterSave { stats, ..save }),
+        );
+        boss.phase_index = boss.current_phase_index();
+        boss.phase_turns = phase_turns;
+        boss
+    }
+
+    fn from_monster(definition: &MonsterDefinition, monster: DefinedMonster) -> Self {
+        Self {
+            monster,
+            phases: definition.phases.clone(),
+            phase_index: 0,
+            phase_turns: 0,
+        }
+    }
+
+    fn current_phase_index(&self) -> usize {
+        #[allow(clippy::cast_precision_loss)]
+        let health_ratio =
+            self.monster.get_stats().health as f32 / self.monster.max_health() as f32;
+
+        self.phases
+            .iter()
+            .rposition(|phase| health_ratio <= phase.health_threshold)
+            .unwrap_or(0)
+    }
+
+    /// Moves to the phase matching the current health, starting its turns over.
+    fn update_phase(&mut self) {
+        let phase_index = self.current_phase_index();
+        if phase_index != self.phase_index {
+            self.phase_index = phase_index;
+            self.phase_turns = 0;
+        }
+    }
+
+    fn current_phase(&self) -> Option<&BossPhase> {
+        self.phases.get(self.phase_index)
+    }
+
+    fn special_attack(&self) -> Option<&SpecialAttack> {
+        self.current_phase()
+            .and_then(|phase| phase.special_attack.as_ref())
+            .filter(|special| (self.phase_turns + 1).is_multiple_of(special.every_turns.max(1)))
+    }
+}
+
+impl Monster for Boss {
+    fn is_alive(&self) -> bool {
+        self.monster.is_alive()
+    }
+
+    fn attack(&mut self) -> Attack {
+        let stats = self.get_stats();
+        let mut attack = Attack {
+            power: stats.attack,
+            speed: stats.speed,
+            ..self.monster.attack()
+        };
+
+        // Special attacks always land
+        if let Some(special) = self.special_attack() {
+            attack.damage_multiplier = f64::from(special.damage_multiplier);
+            attack.unerring = true;
+            if special.ignores_defence {
+                attack.defence_penetration = 1.0;
+            }
+        }
+
+        self.phase_turns += 1;
+        attack
+    }
+
+    fn get_stats(&self) -> MonsterStats {
+        let mut stats = self.monster.get_stats();
+
+        if let Some(phase) = self.current_phase() {
+            stats.attack += phase.attack_bonus;
+            stats.defence += phase.defence_bonus;
+            stats.speed += phase.speed_bonus;
+        }
+
+        stats
+    }
+
+    fn take_damage(&mut self, damage: i32) {
+        self.monster.take_damage(damage);
+        self.update_phase();
+    }
+
+    fn heal(&mut self, amount: i32) -> i32 {
+        let healed = self.monster.heal(amount);
+        self.update_phase();
+        healed
+    }
+
+    fn statuses(&self) -> &StatusEffects {
+        self.monster.statuses()
+    }
+
+    fn statuses_mut(&mut self) -> &mut StatusEffects {
+        self.monster.statuses_mut()
+    }
+
+    fn save(&self) -> MonsterSave {
+        MonsterSave {
+            phase_turns: self.phase_turns,
+            ..self.monster.save()
+        }
+    }
+
+    fn on_hit_effect(&self) -> Option<OnHitEffect> {
+        self.monster.on_hit_effect()
+    }
+
+    fn is_boss(&self) -> bool {
+        true
+    }
+
+    fn phase_name(&self) -> Option<String> {
+        self.current_phase().map(|phase| phase.name.clone())
+    }
+
+    fn next_special_attack(&self) -> Option<String> {
+        self.special_attack().map(|special| special.name.clone())
+    }
+}
@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    damage::Attack,
+    entity::WeaponType,
+    monsters::{Monster, MonsterSave, MonsterStats, MONSTER_CRITICAL_CHANCE},
+    status::{OnHitEffect, StatusEffects, StatusKind},
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BaseStats {
+    pub health: i32,
+    pub attack: i32,
+    pub defence: i32,
+    pub speed: i32,
+}
+
+/// Stats gained per monster level. Fractional growth is rounded down, so a
+/// growth of `0.5` grants one point every two levels.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatGrowth {
+    pub health: f32,
+    pub attack: f32,
+    pub defence: f32,
+    pub speed: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonsterDefinition {
+    pub name: String,
+    pub base: BaseStats,
+    pub growth: StatGrowth,
+    /// Minimum damage dealt by an attack, whatever the player's defence.
+    pub damage_floor: i32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Only bosses have phases, ordered from full health downwards.
+    #[serde(default)]
+    pub phases: Vec<BossPhase>,
+    #[serde(default)]
+    pub reward: Option<BossReward>,
+    #[serde(default)]
+    pub on_hit: Option<OnHitEffect>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BossPhase {
+    pub name: String,
+    /// The phase starts once the boss health ratio drops to this value.
+    pub health_threshold: f32,
+    #[serde(default)]
+    pub attack_bonus: i32,
+    #[serde(default)]
+    pub defence_bonus: i32,
+    #[serde(default)]
+    pub speed_bonus: i32,
+    #[serde(default)]
+    pub special_attack: Option<SpecialAttack>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecialAttack {
+    pub name: String,
+    /// Replaces every n-th attack of the phase.
+    pub every_turns: u32,
+    pub damage_multiplier: f32,
+    #[serde(default)]
+    pub ignores_defence: bool,
+}
+
+/// Loot guaranteed when the boss falls, on top of clearing the zone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossReward {
+    pub gold: u32,
+    pub weapon_type: WeaponType,
+}
+
+impl MonsterDefinition {
+    #[must_use]
+    pub fn stats_at_level(&self, level: i32) -> MonsterStats {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+        let grow = |base: i32, growth: f32| base + (growth * level as f32).floor() as i32;
+
+        MonsterStats {
+            name: self.name.clone(),
+            level,
+            health: grow(self.base.health, self.growth.health),
+            attack: grow(self.base.attack, self.growth.attack),
+            defence: grow(self.base.defence, self.growth.defence),
+            speed: grow(self.base.speed, self.growth.speed),
+        }
+    }
+
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    #[must_use]
+    pub fn is_boss(&self) -> bool {
+        !self.phases.is_empty()
+    }
+}
+
+/// A monster built from a `MonsterDefinition`.
+#[derive(Debug)]
+pub struct DefinedMonster {
+    stats: MonsterStats,
+    max_health: i32,
+    damage_floor: i32,
+    tags: Vec<String>,
+    on_hit: Option<OnHitEffect>,
+    statuses: StatusEffects,
+}
+
+impl DefinedMonster {
+    #[must_use]
+    pub fn new(definition: &MonsterDefinition, level: i32) -> Self {
+        Self::from_stats(definition, definition.stats_at_level(level))
+    }
+
+    #[must_use]
+    pub fn from_stats(definition: &MonsterDefinition, stats: MonsterStats) -> Self {
+        Self {
+            max_health: definition.stats_at_level(stats.level).health,
+            stats,
+            damage_floor: definition.damage_floor,
+            tags: definition.tags.clone(),
+            on_hit: definition.on_hit,
+            statuses: StatusEffects::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn from_save(definition: &MonsterDefinition, save: MonsterSave) -> Self {
+        Self {
+            statuses: save.statuses,
+            ..Self::from_stats(definition, save.stats)
+        }
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub(super) const fn max_health(&self) -> i32 {
+        self.max_health
+    }
+}
+
+impl Monster for DefinedMonster {
+    fn is_alive(&self) -> bool {
+        self.stats.health > 0
+    }
+
+    fn attack(&mut self) -> Attack {
+        let stats = self.get_stats();
+
+        Attack {
+            critical_chance: MONSTER_CRITICAL_CHANCE,
+            damage_floor: self.damage_floor,
+            ..Attack::new(stats.attack, stats.speed)
+        }
+    }
+
+    fn get_stats(&self) -> MonsterStats {
+        let mut stats = self.stats.clone();
+        stats.attack -= self.statuses.potency(StatusKind::Weakness);
+        stats
+    }
+
+    fn take_damage(&mut self, damage: i32) {
+        self.stats.health -= damage;
+    }
+
+    fn heal(&mut self, amount: i32) -> i32 {
+        let healed = amount.min(self.max_health - self.stats.health).max(0);
+        self.stats.health += healed;
+        healed
+    }
+
+    fn statuses(&self) -> &StatusEffects {
+        &self.statuses
+    }
+
+    fn statuses_mut(&mut self) -> &mut StatusEffects {
+        &mut self.statuses
+    }
+
+    fn save(&self) -> MonsterSave {
+        MonsterSave {
+            stats: self.stats.clone(),
+            statuses: self.statuses.clone(),
+            phase_turns: 0,
+        }
+    }
+
+    fn on_hit_effect(&self) -> Option<OnHitEffect> {
+        self.on_hit
+    }
+}
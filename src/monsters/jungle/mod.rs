@@ -1,3 +0,0 @@
-pub mod goblin;
-pub mod ogre;
-pub mod slime;
@@ -1,18 +1,70 @@
 use std::fmt::Debug;
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
-    entity::Player,
-    monsters::jungle::{goblin::Goblin, ogre::Ogre, slime::Slime},
+    damage::{Attack, Defender},
+    monsters::{boss::Boss, definition::DefinedMonster, registry::MonsterRegistry},
+    status::{OnHitEffect, StatusEffects},
 };
 
 pub mod balancer;
-pub mod jungle;
+pub mod boss;
+pub mod definition;
+pub mod registry;
+
+const EXPERIENCE_PER_LEVEL: u32 = 5;
+const BOSS_EXPERIENCE_MULTIPLIER: u32 = 4;
+/// Chance for a monster's blow to be critical.
+pub const MONSTER_CRITICAL_CHANCE: f64 = 0.05;
 
 pub trait Monster {
     fn is_alive(&self) -> bool;
-    fn attack(&self, target: &mut Player);
+    /// Prepares the monster's next blow, to be resolved against the player.
+    fn attack(&mut self) -> Attack;
     fn get_stats(&self) -> MonsterStats;
     fn take_damage(&mut self, damage: i32);
+    /// Restores health up to the maximum and returns how much was actually healed.
+    fn heal(&mut self, amount: i32) -> i32;
+    fn statuses(&self) -> &StatusEffects;
+    fn statuses_mut(&mut self) -> &mut StatusEffects;
+    /// State of the monster to write in a save.
+    fn save(&self) -> MonsterSave;
+
+    /// Status the monster's hits may inflict.
+    fn on_hit_effect(&self) -> Option<OnHitEffect> {
+        None
+    }
+
+    fn defender(&self) -> Defender {
+        let stats = self.get_stats();
+        Defender::new(stats.defence, stats.speed)
+    }
+
+    fn is_boss(&self) -> bool {
+        false
+    }
+
+    /// Name of the phase a boss is currently in.
+    fn phase_name(&self) -> Option<String> {
+        None
+    }
+
+    /// Name of the special attack the next `attack` call will use, if any.
+    fn next_special_attack(&self) -> Option<String> {
+        None
+    }
+
+    /// Experience granted to the player for slaying this monster.
+    fn experience_reward(&self) -> u32 {
+        let reward = self.get_stats().level.unsigned_abs() * EXPERIENCE_PER_LEVEL;
+
+        if self.is_boss() {
+            reward * BOSS_EXPERIENCE_MULTIPLIER
+        } else {
+            reward
+        }
+    }
 }
 
 impl Debug for dyn Monster {
@@ -21,7 +73,7 @@ impl Debug for dyn Monster {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonsterStats {
     pub name: String,
     pub level: i32,
@@ -31,13 +83,35 @@ pub struct MonsterStats {
     pub speed: i32,
 }
 
+/// A monster as written in a save: stats before any phase bonus or status,
+/// and the state of the fight that cannot be derived from them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonsterSave {
+    #[serde(flatten)]
+    pub stats: MonsterStats,
+    #[serde(default)]
+    pub statuses: StatusEffects,
+    /// Attacks made by a boss in its current phase.
+    #[serde(default)]
+    pub phase_turns: u32,
+}
+
 #[must_use]
 pub fn create_monster(name: &str, level: i32) -> Option<Box<dyn Monster>> {
-    match name {
-        "Slime" => Some(Box::new(Slime::new(level))),
-        "Goblin" => Some(Box::new(Goblin::new(level))),
-        "Ogre" => Some(Box::new(Ogre::new(level))),
+    let definition = MonsterRegistry::global().get(name)?;
 
-        _ => None,
+    if definition.is_boss() {
+        return Some(Box::new(Boss::new(definition, level)));
     }
-}
\ No newline at end of file
+    Some(Box::new(DefinedMonster::new(definition, level)))
+}
+
+#[must_use]
+pub fn restore_monster(save: MonsterSave) -> Option<Box<dyn Monster>> {
+    let definition = MonsterRegistry::global().get(&save.stats.name)?;
+
+    if definition.is_boss() {
+        return Some(Box::new(Boss::from_save(definition, save)));
+    }
+    Some(Box::new(DefinedMonster::from_save(definition, save)))
+}
@@ -0,0 +1,87 @@
+use std::sync::OnceLock;
+
+use color_eyre::{eyre::eyre, Result};
+
+use crate::{
+    data::{self, DataSet},
+    monsters::definition::MonsterDefinition,
+};
+
+pub const MONSTER_DATA_DIR: &str = "data/monsters";
+
+const BUILTIN_MONSTERS: &[&str] = &[
+    include_str!("../../data/monsters/slime.json"),
+    include_str!("../../data/monsters/goblin.json"),
+    include_str!("../../data/monsters/ogre.json"),
+    include_str!("../../data/monsters/giant_spider.json"),
+    include_str!("../../data/monsters/bat.json"),
+    include_str!("../../data/monsters/cave_troll.json"),
+    include_str!("../../data/monsters/crystal_wyrm.json"),
+    include_str!("../../data/monsters/skeleton.json"),
+    include_str!("../../data/monsters/wraith.json"),
+    include_str!("../../data/monsters/lich_king.json"),
+    include_str!("../../data/monsters/fire_imp.json"),
+    include_str!("../../data/monsters/magma_golem.json"),
+    include_str!("../../data/monsters/ember_dragon.json"),
+];
+
+static REGISTRY: OnceLock<MonsterRegistry> = OnceLock::new();
+
+#[derive(Debug, Default, Clone)]
+pub struct MonsterRegistry {
+    definitions: Vec<MonsterDefinition>,
+}
+
+impl DataSet for MonsterRegistry {
+    type Definition = MonsterDefinition;
+
+    const DIR: &'static str = MONSTER_DATA_DIR;
+    const BUILTIN: &'static [&'static str] = BUILTIN_MONSTERS;
+
+    fn from_definitions(definitions: Vec<MonsterDefinition>) -> Result<Self> {
+        for (index, definition) in definitions.iter().enumerate() {
+            if definitions[..index]
+                .iter()
+                .any(|known| known.name == definition.name)
+            {
+                return Err(eyre!("Monster defined twice: {}", definition.name));
+            }
+        }
+
+        if definitions.is_empty() {
+            return Err(eyre!("No monster definitions found"));
+        }
+
+        Ok(Self { definitions })
+    }
+}
+
+impl MonsterRegistry {
+    /// The registry shared by the whole game.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        data::global(&REGISTRY)
+    }
+
+    /// Loads the shared registry, reporting invalid monster files before the game starts.
+    pub fn init() -> Result<()> {
+        data::init_global(&REGISTRY)
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&MonsterDefinition> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.name == name)
+    }
+
+    /// Names of the monsters carrying the given tag.
+    #[must_use]
+    pub fn names_with_tag(&self, tag: &str) -> Vec<String> {
+        self.definitions
+            .iter()
+            .filter(|definition| definition.has_tag(tag))
+            .map(|definition| definition.name.clone())
+            .collect()
+    }
+}
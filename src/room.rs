@@ -2,20 +2,38 @@ use core::fmt;
 use std::fmt::{Debug, Display};
 
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    entity::{Weapon, WeaponType},
-    monsters::{jungle::slime::Slime, Monster},
-    zones::zone::Zone,
+    entity::Weapon,
+    equipment::Armor,
+    loot::{generate_random_armor, generate_random_weapon, roll_rarity},
+    monsters::{create_monster, Monster},
+    shop::Shop,
+    zones::zone::{Zone, ZoneDifficulty},
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RoomType {
     Entrance, // Salle d'entrée - moins de monstres
     Normal,   // Salle normale - combats standards
     Elite,    // Salle d'élite - monstres plus forts
     Treasure, // Salle de trésor - peu de monstres mais bon loot
     Boss,     // Salle de boss - combat unique contre le boss
+    Merchant, // Salle du marchand - pas de combat, achat et vente
+}
+
+impl fmt::Display for RoomType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Entrance => write!(f, "Entrance"),
+            Self::Normal => write!(f, "Normal"),
+            Self::Elite => write!(f, "Elite"),
+            Self::Treasure => write!(f, "Treasure"),
+            Self::Boss => write!(f, "Boss"),
+            Self::Merchant => write!(f, "Merchant"),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -26,7 +44,9 @@ pub struct Room {
     pub monsters: Vec<Box<dyn Monster>>,
     pub treasures: Vec<Treasure>,
     pub is_cleared: bool,
-    pub current_monster: usize,
+    /// Index of the monster slain last, shown once the fight is over.
+    pub last_slain: Option<usize>,
+    pub shop: Option<Shop>,
 }
 
 pub trait TreasureUtils {
@@ -38,12 +58,10 @@ impl TreasureUtils for Vec<Treasure> {
         let mut res = 0;
 
         for treasure in self {
-            if treasure.weapon.is_some()
-                || treasure.gold.is_some()
-                || treasure.health_potion.is_some()
-            {
-                res += 1;
-            }
+            res += u16::from(treasure.weapon.is_some())
+                + u16::from(treasure.armor.is_some())
+                + u16::from(treasure.gold.is_some())
+                + u16::from(treasure.health_potion.is_some());
         }
         res
     }
@@ -81,24 +99,47 @@ impl Room {
         zone: Zone,
         room_type: RoomType,
         monsters: Vec<Box<dyn Monster>>,
+        treasures: Vec<Treasure>,
     ) -> Self {
         Self {
             room_number,
             zone,
             room_type,
             monsters,
-            treasures: vec![Treasure::default()],
+            treasures,
             is_cleared: false,
-            current_monster: 0,
+            last_slain: None,
+            shop: None,
         }
     }
 
-    pub const fn monster_slain(&mut self) {
-        self.current_monster += 1;
+    #[must_use]
+    pub fn with_shop(mut self, shop: Shop) -> Self {
+        self.shop = Some(shop);
+        self
+    }
+
+    /// Records the monster at `index` as slain and returns the experience it was worth.
+    pub fn monster_slain(&mut self, index: usize) -> u32 {
+        self.last_slain = Some(index);
+
+        self.monsters
+            .get(index)
+            .map_or(0, |monster| monster.experience_reward())
+    }
+
+    /// Indices of the monsters still standing, in room order.
+    pub fn living_monsters(&self) -> impl Iterator<Item = usize> + '_ {
+        self.monsters
+            .iter()
+            .enumerate()
+            .filter(|(_, monster)| monster.is_alive())
+            .map(|(index, _)| index)
     }
 
-    pub const fn is_empty(&mut self) -> bool {
-        self.monsters.len() == self.current_monster
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.living_monsters().next().is_none()
     }
 }
 
@@ -108,41 +149,66 @@ impl Default for Room {
             room_number: 1,
             zone: Zone::default(),
             room_type: RoomType::Entrance,
-            monsters: vec![Box::new(Slime::new(1))],
-            treasures: vec![Treasure::default()],
+            monsters: create_monster("Slime", 1).into_iter().collect(),
+            treasures: Vec::new(),
             is_cleared: true,
-            current_monster: 0,
+            last_slain: None,
+            shop: None,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+const POTION_DROP_CHANCE: f64 = 0.4;
+const ARMOR_DROP_CHANCE: f64 = 0.35;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Treasure {
     pub weapon: Option<Weapon>,
+    pub armor: Option<Armor>,
     pub gold: Option<u32>,
     pub health_potion: Option<HealthPotion>,
 }
 
 impl Treasure {
-    const fn new(
+    #[must_use]
+    pub const fn new(
         weapon: Option<Weapon>,
+        armor: Option<Armor>,
         gold: Option<u32>,
         health_potion: Option<HealthPotion>,
     ) -> Self {
         Self {
             weapon,
+            armor,
             gold,
             health_potion,
         }
     }
-}
 
-impl Default for Treasure {
-    fn default() -> Self {
+    pub fn generate<R: Rng>(
+        difficulty: &ZoneDifficulty,
+        room_type: &RoomType,
+        rng: &mut R,
+    ) -> Self {
+        let rarity = roll_rarity(difficulty, room_type, rng);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let gold = (f64::from(rng.random_range(10..50)) * difficulty.gold_multiplier()) as u32;
+
+        let health_potion = rng
+            .random_bool(POTION_DROP_CHANCE)
+            .then(|| HealthPotion::new(rng.random_range(4..=8)));
+
+        let armor = rng.random_bool(ARMOR_DROP_CHANCE).then(|| {
+            let rarity = roll_rarity(difficulty, room_type, rng);
+            generate_random_armor(rarity, rng)
+        });
+
         Self::new(
-            Some(Weapon::new(WeaponType::Sword)),
-            Some(rand::rng().random_range(10..50)),
-            None
+            Some(generate_random_weapon(rarity, rng)),
+            armor,
+            Some(gold),
+            health_potion,
         )
     }
 }
@@ -172,11 +238,23 @@ impl WeaponUtils for Vec<Treasure> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthPotion {
     heal_amount: i32,
 }
 
+impl HealthPotion {
+    #[must_use]
+    pub const fn new(heal_amount: i32) -> Self {
+        Self { heal_amount }
+    }
+
+    #[must_use]
+    pub const fn heal_amount(&self) -> i32 {
+        self.heal_amount
+    }
+}
+
 impl Display for HealthPotion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "A potion that heals {}hp", self.heal_amount)
@@ -0,0 +1,233 @@
+use std::{fs, io::ErrorKind, path::Path};
+
+use color_eyre::{eyre::eyre, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::App,
+    dungeon::Dungeon,
+    entity::Player,
+    game::{CombatRng, Phase},
+    initiative::Timeline,
+    monsters::{restore_monster, MonsterSave},
+    room::{Room, RoomType, Treasure},
+    screen::Screen,
+    shop::Shop,
+    utils::{ChangeWeaponOption, CombatOption},
+    zones::{generator::RoomGenerator, map::DungeonMap, zone::Zone},
+};
+
+pub const SAVE_FILE_PATH: &str = "ardentia_save.json";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveData {
+    pub player: Player,
+    pub dungeon: DungeonSave,
+    /// Missing from older saves, which restart the combat rolls from the seed.
+    #[serde(default)]
+    pub combat_rng: Option<CombatRng>,
+    /// Missing from older saves, where the screen tells where the run stood.
+    #[serde(default)]
+    pub phase: Option<Phase>,
+    /// Turns of the fight under way. Older saves start it over.
+    #[serde(default)]
+    pub timeline: Timeline,
+    /// Experience from the monsters already slain in the fight under way.
+    #[serde(default)]
+    pub experience_earned: u32,
+    pub current_screen: Screen,
+    pub current_combat_option: CombatOption,
+    pub current_change_weapon_option: ChangeWeaponOption,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DungeonSave {
+    pub current_zone: Zone,
+    pub current_room_number: i32,
+    /// Missing from saves older than the map, which only had the entrance's column.
+    #[serde(default)]
+    pub current_column: Option<usize>,
+    pub seed: u64,
+    pub is_active: bool,
+    pub current_room: RoomSave,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoomSave {
+    pub room_number: i32,
+    pub zone: Zone,
+    pub room_type: RoomType,
+    pub monsters: Vec<MonsterSave>,
+    pub treasures: Vec<Treasure>,
+    pub is_cleared: bool,
+    #[serde(default)]
+    pub last_slain: Option<usize>,
+    pub shop: Option<Shop>,
+}
+
+impl From<&Room> for RoomSave {
+    fn from(room: &Room) -> Self {
+        Self {
+            room_number: room.room_number,
+            zone: room.zone.clone(),
+            room_type: room.room_type.clone(),
+            monsters: room.monsters.iter().map(|monster| monster.save()).collect(),
+            treasures: room.treasures.clone(),
+            is_cleared: room.is_cleared,
+            last_slain: room.last_slain,
+            shop: room.shop.clone(),
+        }
+    }
+}
+
+impl TryFrom<RoomSave> for Room {
+    type Error = color_eyre::Report;
+
+    fn try_from(save: RoomSave) -> Result<Self> {
+        let mut monsters = Vec::with_capacity(save.monsters.len());
+
+        for monster in save.monsters {
+            let name = monster.stats.name.clone();
+            let monster =
+                restore_monster(monster).ok_or_else(|| eyre!("Unknown monster in save: {name}"))?;
+            monsters.push(monster);
+        }
+
+        Ok(Self {
+            room_number: save.room_number,
+            zone: save.zone,
+            room_type: save.room_type,
+            monsters,
+            treasures: save.treasures,
+            is_cleared: save.is_cleared,
+            last_slain: save.last_slain,
+            shop: save.shop,
+        })
+    }
+}
+
+impl From<&Dungeon> for DungeonSave {
+    fn from(dungeon: &Dungeon) -> Self {
+        Self {
+            current_zone: dungeon.current_zone.clone(),
+            current_room_number: dungeon.current_room_number,
+            current_column: Some(dungeon.current_column),
+            seed: dungeon.room_generator.seed(),
+            is_active: dungeon.is_active,
+            current_room: RoomSave::from(&dungeon.current_room),
+        }
+    }
+}
+
+impl TryFrom<DungeonSave> for Dungeon {
+    type Error = color_eyre::Report;
+
+    fn try_from(save: DungeonSave) -> Result<Self> {
+        // The map is not saved, the seed lays it out again identically
+        let room_generator = RoomGenerator::new(save.seed);
+        let map = DungeonMap::generate(&save.current_zone, &room_generator);
+        // Older saves come from a single path through the middle of the map
+        let current_column = save.current_column.unwrap_or_else(|| {
+            map.layer(save.current_room_number)
+                .iter()
+                .map(|node| node.column)
+                .min_by_key(|column| column.abs_diff(map.entrance()))
+                .unwrap_or_else(|| map.entrance())
+        });
+
+        if map.node(save.current_room_number, current_column).is_none() {
+            return Err(eyre!(
+                "Room {} has no column {current_column} on the map",
+                save.current_room_number
+            ));
+        }
+
+        Ok(Self {
+            current_zone: save.current_zone,
+            current_room_number: save.current_room_number,
+            current_column,
+            map,
+            room_generator,
+            is_active: save.is_active,
+            current_room: Room::try_from(save.current_room)?,
+        })
+    }
+}
+
+impl App {
+    /// Writes the current run to the save file, overwriting any previous save.
+    pub fn save_game(&self, path: impl AsRef<Path>) -> Result<()> {
+        let save = SaveData {
+            player: self.game.player.clone(),
+            dungeon: DungeonSave::from(&self.game.dungeon),
+            combat_rng: Some(self.game.combat_rng.clone()),
+            phase: Some(self.game.phase),
+            timeline: self.game.timeline.clone(),
+            experience_earned: self.game.experience_earned,
+            current_screen: self.current_screen.clone(),
+            current_combat_option: self.current_combat_option.clone(),
+            current_change_weapon_option: self.current_change_weapon_option.clone(),
+        };
+
+        fs::write(path, serde_json::to_string_pretty(&save)?)?;
+        Ok(())
+    }
+
+    /// Restores the run stored in the save file, resuming on the screen it was saved on.
+    pub fn load_game(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let save: SaveData = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+        self.game.dungeon = Dungeon::try_from(save.dungeon)?;
+        self.game.combat_rng = save
+            .combat_rng
+            .unwrap_or_else(|| CombatRng::new(self.game.dungeon.room_generator.seed()));
+        self.game.phase = save
+            .phase
+            .unwrap_or_else(|| phase_of_screen(&save.current_screen));
+        self.game.timeline = save.timeline;
+        self.game.experience_earned = save.experience_earned;
+        self.game.player = save.player;
+        self.combat_log.clear();
+        self.current_combat_option = save.current_combat_option;
+        self.current_change_weapon_option = save.current_change_weapon_option;
+        // The target is not saved, and the monster it pointed at may be down
+        self.reset_target();
+        self.switch_screen(save.current_screen);
+        Ok(())
+    }
+
+    /// Keeps the save file in step with the run: stable screens are saved so the
+    /// run can be resumed, and a finished run leaves nothing behind to load.
+    pub fn persist_run(&self) -> Result<()> {
+        match self.current_screen {
+            Screen::Room | Screen::Combat | Screen::RoomResult | Screen::Shop | Screen::Map => {
+                self.save_game(SAVE_FILE_PATH)
+            }
+            Screen::DeadPlayer => Self::delete_save(SAVE_FILE_PATH),
+            Screen::ZoneCleared => {
+                self.campaign.save()?;
+                Self::delete_save(SAVE_FILE_PATH)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Removes the save file once the run it describes is over.
+    pub fn delete_save(path: impl AsRef<Path>) -> Result<()> {
+        match fs::remove_file(path) {
+            Err(error) if error.kind() != ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Where a run saved on this screen stood, for saves that predate the phase.
+const fn phase_of_screen(screen: &Screen) -> Phase {
+    match screen {
+        Screen::Combat | Screen::ItemPicker | Screen::SkillPicker => Phase::Fighting,
+        Screen::RoomResult => Phase::Looting,
+        Screen::Shop => Phase::Shopping,
+        Screen::Map => Phase::ChoosingPath,
+        _ => Phase::Exploring,
+    }
+}
@@ -1,39 +1,90 @@
-use std::ops::Index;
+use std::{ops::Index, time::Duration};
 
 use ratatui::{
-    layout::{Constraint, Layout},
+    layout::{Constraint, Flex, Layout},
     style::Stylize,
-    text::Line,
+    text::{Line, Span},
+    widgets::Paragraph,
     Frame,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::{
     app::App,
-    room::{TreasureUtils, WeaponUtils},
+    class::CharacterClass,
+    initiative::Combatant,
+    room::{Room, TreasureUtils, WeaponUtils},
+    shop::weapon_sell_price,
+    skills::Skill,
     utils::{
         render_based_on_choice, render_centered, render_centered_bold_text, render_centered_solo,
-        render_left_aligned_text_bold, render_list, render_list_centered,
-        render_right_aligned_text_bold, render_title, ChangeWeaponOption, CombatOption,
-        MainMenuOption,
+        render_left_aligned_text, render_left_aligned_text_bold, render_list, render_list_centered,
+        render_panel, render_right_aligned_text_bold, render_title, ChangeWeaponOption,
+        ClassOption, CombatOption, ItemOption, MainMenuOption, MapOption, ShopOption, SkillOption,
+        TargetOption, ZoneOption,
+    },
+    zones::{
+        map::{MapNode, MAP_WIDTH},
+        zone::Zone,
     },
 };
 
-#[derive(Debug, PartialEq, Eq, Default)]
+/// How many turns of the fight's timeline the combat screen shows.
+const UPCOMING_TURNS: usize = 6;
+/// Entries of the combat log shown at once.
+const COMBAT_LOG_LINES: u16 = 6;
+/// Layers of the dungeon map shown at once, the current one included.
+const MAP_LAYERS: i32 = 6;
+/// Width of a column of the dungeon map, in characters.
+const MAP_CELL_WIDTH: usize = 14;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Screen {
     #[default]
     MainMenu,
+    NameEntry,
+    ClassSelection,
+    ZoneSelection,
     DungeonLoading,
     RoomLoading,
     Room,
     RoomResult,
     Combat,
+    ItemPicker,
+    SkillPicker,
     CombatLoading,
+    BossIntro,
     DefeatMonster,
     DeadPlayer,
-    RunScreen,
+    ZoneCleared,
+    Shop,
+    /// The player picks the next room on the dungeon map.
+    Map,
+    LevelUp,
+    /// The player got away from a fight, dropping some gold.
+    Escaped {
+        gold_lost: u32,
+    },
 }
 
 impl Screen {
+    /// Transitional screens are shown for a while before moving on by themselves.
+    #[must_use]
+    pub const fn transition(&self) -> Option<(Duration, Self)> {
+        match self {
+            Self::DungeonLoading => Some((Duration::from_secs(1), Self::RoomLoading)),
+            Self::RoomLoading => Some((Duration::from_secs(1), Self::Room)),
+            Self::CombatLoading => Some((Duration::from_secs(1), Self::Combat)),
+            Self::BossIntro => Some((Duration::from_secs(3), Self::Combat)),
+            Self::DefeatMonster => Some((Duration::from_secs(1), Self::RoomResult)),
+            Self::LevelUp => Some((Duration::from_secs(3), Self::RoomResult)),
+            Self::DeadPlayer => Some((Duration::from_secs(5), Self::MainMenu)),
+            Self::Escaped { .. } => Some((Duration::from_secs(2), Self::Map)),
+            Self::ZoneCleared => Some((Duration::from_secs(3), Self::MainMenu)),
+            _ => None,
+        }
+    }
+
     pub fn main_menu(frame: &mut Frame, app: &App) {
         let areas = Layout::vertical([
             Constraint::Length(3),
@@ -72,12 +123,157 @@ impl Screen {
         );
     }
 
+    pub fn name_entry(frame: &mut Frame, app: &App) {
+        let [title_area, _, question_area, _, name_area, _, hint_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(4),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(2),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        render_title(frame, "A new hero", title_area);
+        render_centered(frame, "What is your name ?", question_area);
+        render_centered_bold_text(frame, format!("{}_", app.hero_name), name_area);
+        render_centered(
+            frame,
+            "Enter to confirm, Backspace to erase or go back",
+            hint_area,
+        );
+    }
+
+    pub fn class_selection(frame: &mut Frame, app: &App) {
+        #[allow(clippy::cast_possible_truncation)]
+        let [title_area, _, classes_area, _, description_area, stats_area, weapon_area, passive_area, _, hint_area] =
+            Layout::vertical([
+                Constraint::Length(3),
+                Constraint::Length(2),
+                Constraint::Length(CharacterClass::ALL.len() as u16),
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(2),
+                Constraint::Length(1),
+            ])
+            .areas(frame.area());
+        let class_areas = Layout::vertical(vec![Constraint::Length(1); CharacterClass::ALL.len()])
+            .split(classes_area);
+
+        render_title(frame, "Choose a class", title_area);
+
+        for (index, (class, area)) in CharacterClass::ALL
+            .iter()
+            .zip(class_areas.iter())
+            .enumerate()
+        {
+            render_based_on_choice(
+                frame,
+                class.to_string(),
+                *area,
+                &app.current_class_option,
+                &ClassOption(index),
+            );
+        }
+
+        let ClassOption(selected) = app.current_class_option;
+        if let Some(class) = CharacterClass::ALL.get(selected) {
+            let stats = class.starting_stats();
+
+            render_centered(frame, class.description(), description_area);
+            render_centered_bold_text(
+                frame,
+                format!(
+                    "Health : {} - Attack : {} - Defence : {} - Speed : {} - Stamina : {}",
+                    stats.health, stats.attack, stats.defence, stats.speed, stats.stamina
+                ),
+                stats_area,
+            );
+            render_centered(
+                frame,
+                format!("Starts with a worn {}", class.starting_weapon()),
+                weapon_area,
+            );
+            render_centered(
+                frame,
+                format!(
+                    "Passive : {} - {}",
+                    class.passive_name(),
+                    class.passive_description()
+                ),
+                passive_area,
+            );
+        }
+
+        render_centered(
+            frame,
+            "Enter to pick this class, Backspace to go back",
+            hint_area,
+        );
+    }
+
+    pub fn zone_selection(frame: &mut Frame, app: &App) {
+        let zones = Zone::get_available_zones(&app.current_zone_difficulty);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let [title_area, _, difficulty_area, _, zones_area, _, hint_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(4),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(zones.len() as u16),
+            Constraint::Length(2),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        let zone_areas =
+            Layout::vertical(vec![Constraint::Length(1); zones.len()]).split(zones_area);
+
+        render_title(frame, "Choose a zone", title_area);
+        render_centered_bold_text(
+            frame,
+            format!("< Difficulty : {} >", app.current_zone_difficulty),
+            difficulty_area,
+        );
+
+        for (index, (zone, area)) in zones.iter().zip(zone_areas.iter()).enumerate() {
+            let status = if app.campaign.is_cleared(&zone.id) {
+                "cleared"
+            } else if app.campaign.is_unlocked(zone) {
+                "open"
+            } else {
+                "locked"
+            };
+
+            render_based_on_choice(
+                frame,
+                format!(
+                    "{} - levels {} to {} ({status})",
+                    zone.name, zone.level_range.0, zone.level_range.1
+                ),
+                *area,
+                &app.current_zone_option,
+                &ZoneOption(index),
+            );
+        }
+
+        render_centered(
+            frame,
+            "Left/Right to change difficulty, Enter to explore, Backspace to go back",
+            hint_area,
+        );
+    }
+
     pub fn dungeon_loading(frame: &mut Frame, app: &App) {
         render_centered_solo(
             frame,
             format!(
                 "You enter the {} level.",
-                app.dungeon.current_zone.zone_type
+                app.game.dungeon.current_zone.name
             ),
         );
     }
@@ -87,7 +283,7 @@ impl Screen {
     }
 
     pub fn room(frame: &mut Frame, app: &App) {
-        let room = &app.dungeon.current_room;
+        let room = &app.game.dungeon.current_room;
         #[allow(clippy::cast_possible_truncation)]
         let monster_number = (room.monsters.len() + 1) as u16;
         let treasure_number = room.treasures.treasure_len() + 1;
@@ -97,6 +293,7 @@ impl Screen {
             Constraint::Length(1),               // Descritpion
             Constraint::Length(1),               // Space
             Constraint::Length(1),               // Difficulty
+            Constraint::Length(1),               // Player
             Constraint::Length(1),               // Space
             Constraint::Length(monster_number),  // Monsters
             Constraint::Length(1),               // Space
@@ -105,30 +302,48 @@ impl Screen {
             Constraint::Length(5),               // Enter
         ]);
 
-        let [title_area, _, description_area, _, difficulty_area, _, monsters_area, _, treasure_area, _, enter_area] =
+        let [title_area, _, description_area, _, difficulty_area, player_area, _, monsters_area, _, treasure_area, _, enter_area] =
             areas.areas(frame.area());
 
         render_title(
             frame,
             format!(
                 "{} : room {}",
-                &app.dungeon.current_zone.zone_type, &app.dungeon.current_room_number
+                &app.game.dungeon.current_zone.name, &app.game.dungeon.current_room_number
             ),
             title_area,
         );
 
         render_centered_bold_text(
             frame,
-            format!(" {} ", &app.dungeon.current_zone.description),
+            format!(" {} ", &app.game.dungeon.current_zone.description),
             description_area,
         );
 
         render_centered_bold_text(
             frame,
-            format!("Difficulty : {}", &app.dungeon.current_zone.difficulty),
+            format!(
+                "Difficulty : {} - Seed : {}",
+                &app.game.dungeon.current_zone.difficulty,
+                app.game.dungeon.room_generator.seed()
+            ),
             difficulty_area,
         );
 
+        render_centered_bold_text(
+            frame,
+            format!(
+                "Level {} ({}/{} XP) - Health : {}/{} - Gold : {}",
+                app.game.player.level,
+                app.game.player.experience,
+                app.game.player.experience_to_next_level(),
+                app.game.player.health,
+                app.game.player.max_health,
+                app.game.player.wallet.balance()
+            ),
+            player_area,
+        );
+
         let mut monster_list = vec![];
 
         if room.monsters.is_empty() {
@@ -164,9 +379,19 @@ impl Screen {
                         .push(Line::from(format!("A {} weapon", weapon.rarity).red()).centered());
                 }
 
+                if let Some(armor) = treasure.armor {
+                    treasure_list.push(
+                        Line::from(format!("A {} {}", armor.rarity, armor.name).red()).centered(),
+                    );
+                }
+
                 if let Some(gold) = treasure.gold {
                     treasure_list.push(Line::from(format!("{gold} gold").red()).centered());
                 }
+
+                if treasure.health_potion.is_some() {
+                    treasure_list.push(Line::from("A health potion".red()).centered());
+                }
             }
         }
 
@@ -176,13 +401,15 @@ impl Screen {
     }
 
     pub fn combat(frame: &mut Frame, app: &App) {
-        let player = &app.player;
-        let room = &app.dungeon.get_current_room_immutable();
-        let monster = room.monsters.index(room.current_monster);
+        let player = &app.game.player;
+        let room = &app.game.dungeon.get_current_room_immutable();
+        let TargetOption(target) = app.current_target_option;
 
-        let [title_area, combat_area, answer_area] = Layout::vertical([
+        let [title_area, combat_area, log_area, answer_area] = Layout::vertical([
             Constraint::Length(3),
             Constraint::Fill(2),
+            // Borders around the log entries
+            Constraint::Length(COMBAT_LOG_LINES + 2),
             Constraint::Fill(1),
         ])
         .areas(frame.area());
@@ -192,41 +419,56 @@ impl Screen {
             Constraint::Percentage(48),
         ])
         .areas(combat_area);
-        let [player_stats_name_area, player_stats_health_area, player_stats_attack_area, player_stats_defence_area, player_stats_speed_area] =
+        let [player_stats_name_area, player_stats_health_area, player_stats_stamina_area, player_stats_attack_area, player_stats_defence_area, player_stats_speed_area, player_stats_gold_area, player_stats_experience_area, player_stats_status_area] =
             Layout::vertical([
                 Constraint::Length(1),
                 Constraint::Length(1),
                 Constraint::Length(1),
                 Constraint::Length(1),
                 Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
             ])
             .areas(player_stats_area);
-        let [monster_stats_name_area, monster_stats_health_area, monster_stats_attack_area, monster_stats_defence_area, monster_stats_speed_area] =
+        // Two lines per monster: its name, then its stats
+        let monster_areas = Layout::vertical(vec![Constraint::Length(1); room.monsters.len() * 2])
+            .split(monster_stats_area);
+        let [turns_area, _, question_area, attack_button_area, skills_button_area, item_button_area, run_button_area] =
             Layout::vertical([
                 Constraint::Length(1),
                 Constraint::Length(1),
                 Constraint::Length(1),
                 Constraint::Length(1),
                 Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
             ])
-            .areas(monster_stats_area);
-        let [question_area, attack_button_area, run_button_area] = Layout::vertical([
-            Constraint::Length(1),
-            Constraint::Length(1),
-            Constraint::Length(1),
-        ])
-        .areas(answer_area);
+            .areas(answer_area);
 
         render_title(frame, "BATTLE", title_area);
 
         // Player
 
-        render_right_aligned_text_bold(frame, player.name.clone(), player_stats_name_area);
+        render_right_aligned_text_bold(
+            frame,
+            format!(
+                "{} the {} - Level {}",
+                player.name, player.class, player.level
+            ),
+            player_stats_name_area,
+        );
         render_right_aligned_text_bold(
             frame,
             format!("Health : {}", player.health),
             player_stats_health_area,
         );
+        render_right_aligned_text_bold(
+            frame,
+            format!("Stamina : {}/{}", player.stamina, player.max_stamina),
+            player_stats_stamina_area,
+        );
         render_right_aligned_text_bold(
             frame,
             format!("Attack : {}", player.get_attack()),
@@ -234,40 +476,93 @@ impl Screen {
         );
         render_right_aligned_text_bold(
             frame,
-            format!("Defence : {}", player.defence),
+            format!("Defence : {}", player.get_defence()),
             player_stats_defence_area,
         );
         render_right_aligned_text_bold(
             frame,
-            format!("Speed : {}", player.speed),
+            format!("Speed : {}", player.get_speed()),
             player_stats_speed_area,
         );
-
-        // Monster
-        render_left_aligned_text_bold(frame, monster.get_stats().name, monster_stats_name_area);
-        render_left_aligned_text_bold(
-            frame,
-            format!("Health : {}", monster.get_stats().health),
-            monster_stats_health_area,
-        );
-        render_left_aligned_text_bold(
+        render_right_aligned_text_bold(
             frame,
-            format!("Attack : {}", monster.get_stats().attack),
-            monster_stats_attack_area,
+            format!("Gold : {}", player.wallet.balance()),
+            player_stats_gold_area,
         );
-        render_left_aligned_text_bold(
+        render_right_aligned_text_bold(
             frame,
-            format!("Defence : {}", monster.get_stats().defence),
-            monster_stats_defence_area,
+            format!(
+                "XP : {}/{}",
+                player.experience,
+                player.experience_to_next_level()
+            ),
+            player_stats_experience_area,
         );
-        render_left_aligned_text_bold(
+        if !player.statuses.is_empty() {
+            render_right_aligned_text_bold(
+                frame,
+                format!("Status : {}", player.statuses),
+                player_stats_status_area,
+            );
+        }
+
+        // Monsters
+        for (index, monster) in room.monsters.iter().enumerate() {
+            let stats = monster.get_stats();
+            let marker = if index == target { ">" } else { " " };
+            let name = match monster.phase_name() {
+                Some(phase) => format!("{marker} {} - Level {} ({phase})", stats.name, stats.level),
+                None => format!("{marker} {} - Level {}", stats.name, stats.level),
+            };
+            let details = if monster.is_alive() {
+                let mut details = format!(
+                    "  Health : {} - Attack : {} - Defence : {} - Speed : {}",
+                    stats.health, stats.attack, stats.defence, stats.speed
+                );
+                if !monster.statuses().is_empty() {
+                    details.push_str(&format!(" - {}", monster.statuses()));
+                }
+                details
+            } else {
+                "  Defeated".to_string()
+            };
+
+            render_left_aligned_text_bold(frame, name, monster_areas[index * 2]);
+            render_left_aligned_text(frame, details, monster_areas[index * 2 + 1]);
+        }
+
+        // Log
+        render_panel(
             frame,
-            format!("Speed : {}", monster.get_stats().speed),
-            monster_stats_speed_area,
+            " Combat log (PageUp/PageDown to scroll) ",
+            app.combat_log.visible(usize::from(COMBAT_LOG_LINES)),
+            log_area,
         );
 
+        // Turn order
+        let upcoming: Vec<String> = app
+            .game
+            .upcoming_turns(UPCOMING_TURNS)
+            .into_iter()
+            .map(|combatant| match combatant {
+                Combatant::Player => player.name.clone(),
+                Combatant::Monster(index) => room.monsters[index].get_stats().name,
+            })
+            .collect();
+        if !upcoming.is_empty() {
+            render_centered(
+                frame,
+                format!("Next turns : {}", upcoming.join(" > ")),
+                turns_area,
+            );
+        }
+
         // Question
-        render_centered(frame, "What do you do ?", question_area);
+        render_centered(
+            frame,
+            "What do you do ? (Left/Right to pick a target)",
+            question_area,
+        );
         render_based_on_choice(
             frame,
             "Attack",
@@ -277,31 +572,332 @@ impl Screen {
         );
         render_based_on_choice(
             frame,
-            "Run",
+            "Skills",
+            skills_button_area,
+            &app.current_combat_option,
+            &CombatOption::Skills,
+        );
+        render_based_on_choice(
+            frame,
+            format!("Use item ({})", app.game.player.inventory.len()),
+            item_button_area,
+            &app.current_combat_option,
+            &CombatOption::UseItem,
+        );
+        render_based_on_choice(
+            frame,
+            app.game.flee_chance().map_or_else(
+                || "Run (no escape)".to_string(),
+                |chance| format!("Run ({:.0}%)", chance * 100.0),
+            ),
             run_button_area,
             &app.current_combat_option,
             &CombatOption::Run,
         );
     }
 
+    pub fn skill_picker(frame: &mut Frame, app: &App) {
+        let player = &app.game.player;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let [title_area, _, stamina_area, _, skills_area, _, description_area, hint_area] =
+            Layout::vertical([
+                Constraint::Length(3),
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(Skill::ALL.len() as u16),
+                Constraint::Length(2),
+                Constraint::Length(2),
+                Constraint::Length(1),
+            ])
+            .areas(frame.area());
+        let skill_areas =
+            Layout::vertical(vec![Constraint::Length(1); Skill::ALL.len()]).split(skills_area);
+
+        render_title(frame, "SKILLS", title_area);
+        render_centered_bold_text(
+            frame,
+            format!("Stamina : {}/{}", player.stamina, player.max_stamina),
+            stamina_area,
+        );
+
+        for (index, (skill, area)) in Skill::ALL.iter().zip(skill_areas.iter()).enumerate() {
+            let cooldown = player.cooldowns.remaining(*skill);
+            let text = if cooldown > 0 {
+                format!("{skill} - ready in {cooldown} turns")
+            } else {
+                format!("{skill} - {} stamina", skill.cost())
+            };
+
+            render_based_on_choice(
+                frame,
+                text,
+                *area,
+                &app.current_skill_option,
+                &SkillOption(index),
+            );
+        }
+
+        let SkillOption(selected) = app.current_skill_option;
+        if let Some(skill) = Skill::ALL.get(selected) {
+            render_centered(frame, skill.description(), description_area);
+        }
+        render_centered(
+            frame,
+            "Enter to use on your target (takes your turn), Backspace to go back",
+            hint_area,
+        );
+    }
+
+    pub fn item_picker(frame: &mut Frame, app: &App) {
+        let player = &app.game.player;
+        let items = player.inventory.items();
+
+        #[allow(clippy::cast_possible_truncation)]
+        let [title_area, _, health_area, _, items_area, _, hint_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(2),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(items.len().max(1) as u16),
+            Constraint::Length(2),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        render_title(frame, "INVENTORY", title_area);
+        render_centered_bold_text(
+            frame,
+            format!("Health : {}/{}", player.health, player.max_health),
+            health_area,
+        );
+
+        if items.is_empty() {
+            render_centered(frame, "Your bag is empty.", items_area);
+        } else {
+            let item_areas =
+                Layout::vertical(vec![Constraint::Length(1); items.len()]).split(items_area);
+
+            for (index, (item, area)) in items.iter().zip(item_areas.iter()).enumerate() {
+                render_based_on_choice(
+                    frame,
+                    item.to_string(),
+                    *area,
+                    &app.current_item_option,
+                    &ItemOption(index),
+                );
+            }
+        }
+
+        render_centered(
+            frame,
+            "Enter to use (takes your turn), Backspace to go back",
+            hint_area,
+        );
+    }
+
+    pub fn shop(frame: &mut Frame, app: &App) {
+        let player = &app.game.player;
+        let stock = app
+            .game
+            .dungeon
+            .current_room
+            .shop
+            .as_ref()
+            .map(|shop| shop.stock.as_slice())
+            .unwrap_or_default();
+
+        #[allow(clippy::cast_possible_truncation)]
+        let [title_area, _, player_area, weapon_area, _, stock_area, _, message_area] =
+            Layout::vertical([
+                Constraint::Length(3),
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length((stock.len() + 2) as u16),
+                Constraint::Length(2),
+                Constraint::Length(1),
+            ])
+            .areas(frame.area());
+
+        render_title(frame, "MERCHANT", title_area);
+        render_centered_bold_text(
+            frame,
+            format!(
+                "Health : {}/{} - Gold : {}",
+                player.health,
+                player.max_health,
+                player.wallet.balance()
+            ),
+            player_area,
+        );
+
+        let (weapon, sell_entry) = player.weapon.as_ref().map_or_else(
+            || {
+                (
+                    "None".to_string(),
+                    "Sell weapon (nothing to sell)".to_string(),
+                )
+            },
+            |weapon| {
+                (
+                    format!("{} ({} attack)", weapon.name, weapon.attack_value),
+                    format!(
+                        "Sell {} for {} gold",
+                        weapon.name,
+                        weapon_sell_price(weapon)
+                    ),
+                )
+            },
+        );
+        render_centered(frame, format!("Weapon : {weapon}"), weapon_area);
+
+        let entries = stock
+            .iter()
+            .map(|item| format!("{item} - {} gold", item.price()))
+            .chain([sell_entry, "Leave".to_string()])
+            .collect::<Vec<_>>();
+        let entry_areas =
+            Layout::vertical(vec![Constraint::Length(1); entries.len()]).split(stock_area);
+
+        for (index, (entry, area)) in entries.into_iter().zip(entry_areas.iter()).enumerate() {
+            render_based_on_choice(
+                frame,
+                entry,
+                *area,
+                &app.current_shop_option,
+                &ShopOption(index),
+            );
+        }
+
+        if let Some(message) = &app.shop_message {
+            render_centered(frame, message.clone(), message_area);
+        }
+    }
+
+    pub fn map(frame: &mut Frame, app: &App) {
+        let dungeon = &app.game.dungeon;
+        let next_rooms = dungeon.next_rooms();
+        let MapOption(index) = app.current_map_option;
+        let selected = next_rooms.get(index).map(|node| node.column);
+
+        // Upcoming layers stack upwards, the current room at the bottom
+        let current = dungeon.current_room_number;
+        let last = (current + MAP_LAYERS - 1).min(dungeon.current_zone.room_count);
+        let mut lines = Vec::new();
+        for room_number in (current..=last).rev() {
+            let layer = dungeon.map.layer(room_number);
+            lines.push(map_layer(layer, |node| {
+                if room_number == current && node.column == dungeon.current_column {
+                    MapMarker::Current
+                } else if room_number == current + 1 && selected == Some(node.column) {
+                    MapMarker::Selected
+                } else if room_number == current + 1 && next_rooms.contains(&node) {
+                    MapMarker::Reachable
+                } else {
+                    MapMarker::None
+                }
+            }));
+            if room_number > current {
+                lines.push(map_links(dungeon.map.layer(room_number - 1)));
+            }
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let [title_area, _, player_area, _, map_area, _, choice_area, hint_area] =
+            Layout::vertical([
+                Constraint::Length(3),
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(lines.len() as u16),
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .areas(frame.area());
+
+        render_title(
+            frame,
+            format!(
+                "{} : room {}/{}",
+                dungeon.current_zone.name, current, dungeon.current_zone.room_count
+            ),
+            title_area,
+        );
+        render_centered_bold_text(
+            frame,
+            format!(
+                "Health : {}/{} - Gold : {}",
+                app.game.player.health,
+                app.game.player.max_health,
+                app.game.player.wallet.balance()
+            ),
+            player_area,
+        );
+        frame.render_widget(Paragraph::new(lines).centered(), map_area);
+
+        if let Some(node) = next_rooms.get(index) {
+            render_centered_bold_text(
+                frame,
+                format!("Next : {} room", node.room_type),
+                choice_area,
+            );
+        }
+        render_centered(frame, "Left/Right to pick a room, Enter to go", hint_area);
+    }
+
     pub fn combat_loading(frame: &mut Frame, app: &App) {
-        let room = &app.dungeon.get_current_room_immutable();
+        let room = &app.game.dungeon.get_current_room_immutable();
 
-        let monster = room.monsters.index(room.current_monster);
+        let monsters: Vec<String> = room
+            .living_monsters()
+            .map(|index| {
+                let stats = room.monsters[index].get_stats();
+                format!("a level {} {}", stats.level, stats.name)
+            })
+            .collect();
 
-        render_centered_solo(
+        let text = match monsters.as_slice() {
+            [monster] => format!("{} appears !", capitalize(monster)),
+            _ => format!("{} appear !", capitalize(&monsters.join(", "))),
+        };
+        render_centered_solo(frame, text);
+    }
+
+    pub fn boss_intro(frame: &mut Frame, app: &App) {
+        let room = &app.game.dungeon.get_current_room_immutable();
+        let boss = room
+            .living_monsters()
+            .map(|index| &room.monsters[index])
+            .find(|monster| monster.is_boss())
+            .unwrap_or_else(|| room.monsters.index(0));
+
+        let [title_area, _, description_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .flex(Flex::Center)
+        .areas(frame.area());
+
+        render_title(frame, boss.get_stats().name.to_uppercase(), title_area);
+        render_centered_bold_text(
             frame,
             format!(
-                "A level {} {} appears !",
-                monster.get_stats().level,
-                monster.get_stats().name
+                "The level {} guardian of the {} blocks your way !",
+                boss.get_stats().level,
+                app.game.dungeon.current_zone.name
             ),
+            description_area,
         );
     }
 
     #[allow(clippy::missing_panics_doc)]
     pub fn room_result(frame: &mut Frame, app: &App) {
-        let room = &app.dungeon.get_current_room_immutable();
+        let room = &app.game.dungeon.get_current_room_immutable();
 
         let treasures = room.treasures.clone();
 
@@ -314,6 +910,7 @@ impl Screen {
 
         treasures_text.push(Line::from("Rewards:").red().bold());
 
+        #[allow(clippy::unnecessary_unwrap)]
         for treasure in treasures.clone() {
             if treasure.weapon.is_some() {
                 treasures_text.push(
@@ -322,6 +919,14 @@ impl Screen {
                         .bold(),
                 );
             }
+            if let Some(armor) = treasure.armor {
+                let verdict = if app.game.player.equipment.is_upgrade(&armor) {
+                    "worn"
+                } else {
+                    "left behind"
+                };
+                treasures_text.push(Line::from(format!("{armor} - {verdict}")).red().bold());
+            }
             if treasure.health_potion.is_some() {
                 treasures_text.push(
                     Line::from(
@@ -360,13 +965,14 @@ impl Screen {
             .areas(change_weapon_area);
 
             let player_attack = app
+                .game
                 .player
                 .weapon
                 .as_ref()
-                .map_or(app.player.attack, |weapon| {
-                    app.player.attack + weapon.attack_value
+                .map_or(app.game.player.attack, |weapon| {
+                    app.game.player.attack + weapon.attack_value
                 });
-            let weapon_attack = app.player.attack
+            let weapon_attack = app.game.player.attack
                 + treasures
                     .get_weapon()
                     .expect("Should not be empty")
@@ -403,17 +1009,38 @@ impl Screen {
     }
 
     pub fn defeat_monster(frame: &mut Frame, app: &App) {
-        let room = &app.dungeon.get_current_room_immutable();
+        let room = &app.game.dungeon.get_current_room_immutable();
 
-        let monster = room.monsters.index(room.current_monster - 1);
+        render_centered_solo(frame, defeat_message(room));
+    }
 
-        render_centered_solo(
+    pub fn level_up(frame: &mut Frame, app: &App) {
+        let player = &app.game.player;
+        let room = &app.game.dungeon.get_current_room_immutable();
+
+        let [defeat_area, _, title_area, _, stats_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .flex(Flex::Center)
+        .areas(frame.area());
+
+        render_centered(frame, defeat_message(room), defeat_area);
+        render_title(frame, format!("LEVEL {}", player.level), title_area);
+        render_centered_bold_text(
             frame,
             format!(
-                "You defeated a level {} {} !",
-                monster.get_stats().level,
-                monster.get_stats().name
+                "Health : {}/{} - Attack : {} - Defence : {} - Speed : {}",
+                player.health,
+                player.max_health,
+                player.get_attack(),
+                player.get_defence(),
+                player.get_speed()
             ),
+            stats_area,
         );
     }
 
@@ -421,7 +1048,86 @@ impl Screen {
         render_centered_solo(frame, "You are dead !");
     }
 
-    pub fn run_screen(frame: &mut Frame) {
-        render_centered_solo(frame, "You ran away... from the dungeon...");
+    pub fn escaped(frame: &mut Frame, gold_lost: u32) {
+        render_centered_solo(
+            frame,
+            format!("You got away, leaving the treasure behind and dropping {gold_lost} gold..."),
+        );
     }
+
+    pub fn zone_cleared(frame: &mut Frame, app: &App) {
+        render_centered_solo(
+            frame,
+            format!("You cleared the {} !", app.game.dungeon.current_zone.name),
+        );
+    }
+}
+
+/// How a room of the dungeon map stands out.
+enum MapMarker {
+    None,
+    Current,
+    Reachable,
+    Selected,
+}
+
+/// A layer of the dungeon map, every room in its column.
+fn map_layer<'a>(layer: &[MapNode], marker: impl Fn(&MapNode) -> MapMarker) -> Line<'a> {
+    let spans: Vec<Span> = (0..MAP_WIDTH)
+        .map(|column| {
+            let Some(node) = layer.iter().find(|node| node.column == column) else {
+                return Span::raw(" ".repeat(MAP_CELL_WIDTH));
+            };
+            let label = node.room_type.to_string();
+
+            match marker(node) {
+                MapMarker::None => format!("{label:^MAP_CELL_WIDTH$}").red().dim(),
+                MapMarker::Current => format!("{:^MAP_CELL_WIDTH$}", format!("[{label}]"))
+                    .red()
+                    .bold(),
+                MapMarker::Reachable => format!("{label:^MAP_CELL_WIDTH$}").red(),
+                MapMarker::Selected => format!("{:^MAP_CELL_WIDTH$}", format!("> {label}"))
+                    .red()
+                    .bold(),
+            }
+        })
+        .collect();
+
+    Line::from(spans)
+}
+
+/// The paths from a layer of the dungeon map up to the next one.
+fn map_links<'a>(layer: &[MapNode]) -> Line<'a> {
+    let mut row = vec![' '; MAP_WIDTH * MAP_CELL_WIDTH];
+    let center = MAP_CELL_WIDTH / 2;
+
+    for node in layer {
+        let position = node.column * MAP_CELL_WIDTH + center;
+        for &next in &node.next {
+            match next.cmp(&node.column) {
+                std::cmp::Ordering::Equal => row[position] = '|',
+                std::cmp::Ordering::Greater => row[position + center] = '/',
+                std::cmp::Ordering::Less => row[position - center] = '\\',
+            }
+        }
+    }
+
+    Line::from(row.into_iter().collect::<String>().red().dim())
+}
+
+fn defeat_message(room: &Room) -> String {
+    match (room.monsters.len(), room.last_slain) {
+        (1, Some(index)) => {
+            let stats = room.monsters[index].get_stats();
+            format!("You defeated a level {} {} !", stats.level, stats.name)
+        }
+        (count, _) => format!("You defeated all {count} monsters !"),
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
 }
@@ -0,0 +1,111 @@
+use std::fmt::{self, Display};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entity::{Rarity, Weapon},
+    equipment::Armor,
+    inventory::Consumable,
+    loot::{generate_random_armor, generate_random_weapon, roll_rarity},
+    room::{HealthPotion, RoomType},
+    zones::zone::ZoneDifficulty,
+};
+
+const WEAPON_STOCK: usize = 3;
+const ARMOR_STOCK: usize = 2;
+const POTION_STOCK: usize = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ShopItem {
+    Weapon(Weapon),
+    Armor(Armor),
+    Consumable(Consumable),
+}
+
+impl ShopItem {
+    #[must_use]
+    pub fn price(&self) -> u32 {
+        match self {
+            Self::Weapon(weapon) => weapon_price(weapon),
+            Self::Armor(armor) => {
+                rarity_base_price(&armor.rarity) + armor.score().max(0).unsigned_abs() * 8
+            }
+            Self::Consumable(Consumable::HealthPotion(potion)) => {
+                potion.heal_amount().unsigned_abs() * 4
+            }
+        }
+    }
+}
+
+impl Display for ShopItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Weapon(weapon) => write!(
+                f,
+                "{} ({}, {} attack)",
+                weapon.name, weapon.rarity, weapon.attack_value
+            ),
+            Self::Armor(armor) => write!(
+                f,
+                "{} ({}, {} defence, {:+} speed)",
+                armor.name, armor.rarity, armor.defence, armor.speed
+            ),
+            Self::Consumable(item) => write!(f, "{item}"),
+        }
+    }
+}
+
+const fn rarity_base_price(rarity: &Rarity) -> u32 {
+    match rarity {
+        Rarity::Common => 20,
+        Rarity::Rare => 50,
+        Rarity::Epic => 120,
+        Rarity::Legendary => 300,
+        Rarity::Mythical => 700,
+    }
+}
+
+#[must_use]
+pub fn weapon_price(weapon: &Weapon) -> u32 {
+    rarity_base_price(&weapon.rarity) + weapon.attack_value.unsigned_abs() * 5
+}
+
+/// Merchants buy weapons back for half of what they sell them for.
+#[must_use]
+pub fn weapon_sell_price(weapon: &Weapon) -> u32 {
+    weapon_price(weapon) / 2
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Shop {
+    pub stock: Vec<ShopItem>,
+}
+
+impl Shop {
+    pub fn generate<R: Rng>(difficulty: &ZoneDifficulty, rng: &mut R) -> Self {
+        let mut stock = Vec::with_capacity(WEAPON_STOCK + ARMOR_STOCK + POTION_STOCK);
+
+        for _ in 0..WEAPON_STOCK {
+            let rarity = roll_rarity(difficulty, &RoomType::Merchant, rng);
+            stock.push(ShopItem::Weapon(generate_random_weapon(rarity, rng)));
+        }
+
+        for _ in 0..ARMOR_STOCK {
+            let rarity = roll_rarity(difficulty, &RoomType::Merchant, rng);
+            stock.push(ShopItem::Armor(generate_random_armor(rarity, rng)));
+        }
+
+        for _ in 0..POTION_STOCK {
+            let potion = HealthPotion::new(rng.random_range(4..=8));
+            stock.push(ShopItem::Consumable(Consumable::HealthPotion(potion)));
+        }
+
+        Self { stock }
+    }
+
+    /// Removes the item at `index` from the stock once it has been paid for.
+    pub fn take(&mut self, index: usize) -> Option<ShopItem> {
+        (index < self.stock.len()).then(|| self.stock.remove(index))
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::status::{StatusEffect, StatusKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Skill {
+    PowerStrike,
+    GuardBreak,
+    Rend,
+    SecondWind,
+}
+
+impl Skill {
+    pub const ALL: [Self; 4] = [
+        Self::PowerStrike,
+        Self::GuardBreak,
+        Self::Rend,
+        Self::SecondWind,
+    ];
+
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::PowerStrike => "A blow dealing twice the damage",
+            Self::GuardBreak => "A strike ignoring the monster's defence",
+            Self::Rend => "A strike leaving the monster bleeding",
+            Self::SecondWind => "Recover a third of your health",
+        }
+    }
+
+    /// Stamina spent to use the skill.
+    #[must_use]
+    pub const fn cost(self) -> i32 {
+        match self {
+            Self::PowerStrike | Self::Rend => 3,
+            Self::GuardBreak => 4,
+            Self::SecondWind => 5,
+        }
+    }
+
+    /// Player turns before the skill can be used again, counting the one it was used in.
+    #[must_use]
+    pub const fn cooldown(self) -> u32 {
+        match self {
+            Self::PowerStrike => 2,
+            Self::GuardBreak | Self::Rend => 3,
+            Self::SecondWind => 5,
+        }
+    }
+
+    /// Whether the skill hits the targeted monster rather than helping the player.
+    #[must_use]
+    pub const fn is_strike(self) -> bool {
+        !matches!(self, Self::SecondWind)
+    }
+
+    /// Health restored to the player.
+    #[must_use]
+    pub const fn healing(self, max_health: i32) -> i32 {
+        match self {
+            Self::SecondWind => max_health / 3,
+            _ => 0,
+        }
+    }
+
+    /// Damage multiplier of the strike.
+    #[must_use]
+    pub const fn damage_multiplier(self) -> f64 {
+        match self {
+            Self::PowerStrike => 2.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Share of the monster's defence ignored on top of the weapon's penetration.
+    #[must_use]
+    pub const fn defence_penetration(self) -> f64 {
+        match self {
+            Self::GuardBreak => 1.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Status inflicted whenever the strike deals damage.
+    #[must_use]
+    pub const fn inflicts(self) -> Option<StatusEffect> {
+        match self {
+            Self::Rend => Some(StatusEffect {
+                kind: StatusKind::Bleed,
+                potency: 2,
+                turns: 3,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Skill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PowerStrike => write!(f, "Power Strike"),
+            Self::GuardBreak => write!(f, "Guard Break"),
+            Self::Rend => write!(f, "Rend"),
+            Self::SecondWind => write!(f, "Second Wind"),
+        }
+    }
+}
+
+/// Turns left before each skill used recently is ready again.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Cooldowns {
+    turns: BTreeMap<Skill, u32>,
+}
+
+impl Cooldowns {
+    #[must_use]
+    pub fn remaining(&self, skill: Skill) -> u32 {
+        self.turns.get(&skill).copied().unwrap_or(0)
+    }
+
+    pub fn start(&mut self, skill: Skill) {
+        self.turns.insert(skill, skill.cooldown());
+    }
+
+    /// Counts down one player turn.
+    pub fn tick(&mut self) {
+        for turns in self.turns.values_mut() {
+            *turns = turns.saturating_sub(1);
+        }
+        self.turns.retain(|_, turns| *turns > 0);
+    }
+
+    pub fn clear(&mut self) {
+        self.turns.clear();
+    }
+}
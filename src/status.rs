@@ -0,0 +1,175 @@
+use std::fmt::{self, Display};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatusKind {
+    /// Damage every turn, fading by one point each time.
+    Poison,
+    /// Steady damage every turn.
+    Bleed,
+    /// The turn is lost while it lasts.
+    Stun,
+    /// Attack is lowered by the potency.
+    Weakness,
+    /// Health restored every turn.
+    Regeneration,
+}
+
+impl StatusKind {
+    /// Beneficial effects go to whoever landed the hit rather than to its target.
+    #[must_use]
+    pub const fn is_beneficial(self) -> bool {
+        matches!(self, Self::Regeneration)
+    }
+
+    /// Damage over time piles up, the other effects only keep the strongest application.
+    #[must_use]
+    pub const fn stacks(self) -> bool {
+        matches!(self, Self::Poison | Self::Bleed)
+    }
+}
+
+impl Display for StatusKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poison => write!(f, "Poison"),
+            Self::Bleed => write!(f, "Bleed"),
+            Self::Stun => write!(f, "Stun"),
+            Self::Weakness => write!(f, "Weakness"),
+            Self::Regeneration => write!(f, "Regeneration"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub potency: i32,
+    /// Turns left before the effect wears off.
+    pub turns: u32,
+}
+
+impl Display for StatusEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            StatusKind::Stun => write!(f, "{}", self.kind),
+            _ => write!(f, "{} {} ({})", self.kind, self.potency, self.turns),
+        }
+    }
+}
+
+/// A status a hit may inflict, as declared by monsters and weapon affixes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct OnHitEffect {
+    pub kind: StatusKind,
+    pub chance: f64,
+    #[serde(default)]
+    pub potency: i32,
+    pub turns: u32,
+}
+
+impl OnHitEffect {
+    pub fn roll<R: Rng>(&self, rng: &mut R) -> Option<StatusEffect> {
+        rng.random_bool(self.chance.clamp(0.0, 1.0))
+            .then_some(StatusEffect {
+                kind: self.kind,
+                potency: self.potency,
+                turns: self.turns,
+            })
+    }
+}
+
+/// What the effects did at the end of their bearer's turn.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StatusTick {
+    pub damage: i32,
+    pub healing: i32,
+    pub expired: Vec<StatusKind>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, effect: StatusEffect) {
+        match self
+            .effects
+            .iter_mut()
+            .find(|active| active.kind == effect.kind)
+        {
+            Some(active) if effect.kind.stacks() => {
+                active.potency += effect.potency;
+                active.turns = active.turns.max(effect.turns);
+            }
+            Some(active) => {
+                active.potency = active.potency.max(effect.potency);
+                active.turns = active.turns.max(effect.turns);
+            }
+            None => self.effects.push(effect),
+        }
+    }
+
+    #[must_use]
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+
+    #[must_use]
+    pub fn potency(&self, kind: StatusKind) -> i32 {
+        self.effects
+            .iter()
+            .find(|effect| effect.kind == kind)
+            .map_or(0, |effect| effect.potency)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.iter()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    /// Plays one turn of every effect and removes those that wore off.
+    pub fn tick(&mut self) -> StatusTick {
+        let mut tick = StatusTick::default();
+
+        for effect in &mut self.effects {
+            match effect.kind {
+                StatusKind::Poison => {
+                    tick.damage += effect.potency;
+                    effect.potency = (effect.potency - 1).max(1);
+                }
+                StatusKind::Bleed => tick.damage += effect.potency,
+                StatusKind::Regeneration => tick.healing += effect.potency,
+                StatusKind::Stun | StatusKind::Weakness => (),
+            }
+            effect.turns = effect.turns.saturating_sub(1);
+        }
+
+        self.effects.retain(|effect| {
+            if effect.turns == 0 {
+                tick.expired.push(effect.kind);
+            }
+            effect.turns > 0
+        });
+
+        tick
+    }
+}
+
+impl Display for StatusEffects {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let effects: Vec<String> = self.effects.iter().map(ToString::to_string).collect();
+        write!(f, "{}", effects.join(", "))
+    }
+}
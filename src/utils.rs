@@ -2,13 +2,14 @@ use ratatui::{
     layout::{Constraint, Flex, Layout, Rect},
     style::Stylize,
     text::{Line, Text},
-    widgets::{Block, Paragraph, Wrap},
+    widgets::{Block, Clear, Paragraph, Wrap},
     Frame,
 };
+use serde::{Deserialize, Serialize};
 
 pub trait Choice {}
 
-#[derive(Debug, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum MainMenuOption {
     #[default]
     NewGame,
@@ -18,16 +19,18 @@ pub enum MainMenuOption {
 
 impl Choice for MainMenuOption {}
 
-#[derive(Debug, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum CombatOption {
     #[default]
     Attack,
+    Skills,
+    UseItem,
     Run,
 }
 
 impl Choice for CombatOption {}
 
-#[derive(Debug, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ChangeWeaponOption {
     #[default]
     Yes,
@@ -36,6 +39,49 @@ pub enum ChangeWeaponOption {
 
 impl Choice for ChangeWeaponOption {}
 
+/// Index of the highlighted item in the inventory picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ItemOption(pub usize);
+
+impl Choice for ItemOption {}
+
+/// Index, in `Skill::ALL`, of the highlighted skill in the skill picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SkillOption(pub usize);
+
+impl Choice for SkillOption {}
+
+/// Index, in `CharacterClass::ALL`, of the highlighted class at character creation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ClassOption(pub usize);
+
+impl Choice for ClassOption {}
+
+/// Index of the highlighted zone on the zone selection screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ZoneOption(pub usize);
+
+impl Choice for ZoneOption {}
+
+/// Index of the highlighted shop entry: the merchant's stock first, then
+/// selling the current weapon and leaving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ShopOption(pub usize);
+
+impl Choice for ShopOption {}
+
+/// Index, among the rooms the current one leads to, of the highlighted room on the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MapOption(pub usize);
+
+impl Choice for MapOption {}
+
+/// Index, in the room, of the monster the player is aiming at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TargetOption(pub usize);
+
+impl Choice for TargetOption {}
+
 pub fn render_title<'a, S: Into<String> + Into<Text<'a>>>(frame: &mut Frame, title: S, area: Rect) {
     let title: String = title.into();
     frame.render_widget(
@@ -122,6 +168,33 @@ pub fn render_list_centered<'a, S: Into<String> + Into<Text<'a>>>(
     );
 }
 
+/// Renders a message on the last line of the screen, over whatever is drawn there.
+pub fn render_notice(frame: &mut Frame, notice: &str) {
+    let area = frame.area();
+    let [notice_area] = Layout::vertical([Constraint::Length(1)])
+        .flex(Flex::End)
+        .areas(area);
+
+    frame.render_widget(Clear, notice_area);
+    frame.render_widget(
+        Line::from(notice.to_string().red().bold()).centered(),
+        notice_area,
+    );
+}
+
+/// Renders the lines in a bordered panel, one entry per line.
+pub fn render_panel(frame: &mut Frame, title: &str, lines: &[String], area: Rect) {
+    let lines: Vec<Line> = lines
+        .iter()
+        .map(|line| Line::from(line.clone().red()))
+        .collect();
+
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(title.red().bold())),
+        area,
+    );
+}
+
 pub fn render_right_aligned_text_bold<'a, S: Into<String> + Into<Text<'a>>>(
     frame: &mut Frame,
     text: S,
@@ -135,6 +208,16 @@ pub fn render_right_aligned_text_bold<'a, S: Into<String> + Into<Text<'a>>>(
     );
 }
 
+pub fn render_left_aligned_text<'a, S: Into<String> + Into<Text<'a>>>(
+    frame: &mut Frame,
+    text: S,
+    area: Rect,
+) {
+    let text: String = text.into();
+
+    frame.render_widget(Paragraph::new(Line::from(text).red()).left_aligned(), area);
+}
+
 pub fn render_left_aligned_text_bold<'a, S: Into<String> + Into<Text<'a>>>(
     frame: &mut Frame,
     text: S,
@@ -0,0 +1,128 @@
+use std::sync::OnceLock;
+
+use color_eyre::{eyre::eyre, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::{self, DataSet},
+    monsters::{definition::MonsterDefinition, registry::MonsterRegistry},
+    zones::zone::ZoneDifficulty,
+};
+
+pub const ZONE_DATA_DIR: &str = "data/zones";
+
+const BUILTIN_ZONES: &[&str] = &[
+    include_str!("../../data/zones/jungle.json"),
+    include_str!("../../data/zones/caves.json"),
+    include_str!("../../data/zones/ruins.json"),
+    include_str!("../../data/zones/volcano.json"),
+];
+
+static CATALOGUE: OnceLock<ZoneCatalogue> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoneLevels {
+    pub difficulty: ZoneDifficulty,
+    pub min_level: i32,
+    pub max_level: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoneDefinition {
+    /// Identifier the campaign and other zones refer to the zone by.
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub recommended_level: i32,
+    /// Monster level range for each difficulty the zone can be played at.
+    pub levels: Vec<ZoneLevels>,
+    pub unique_monsters: Vec<String>,
+    pub boss_name: String,
+    pub room_count: i32,
+    /// Zone whose boss must be beaten before this one can be entered.
+    #[serde(default)]
+    pub unlocked_by: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ZoneCatalogue {
+    definitions: Vec<ZoneDefinition>,
+}
+
+impl DataSet for ZoneCatalogue {
+    type Definition = ZoneDefinition;
+
+    const DIR: &'static str = ZONE_DATA_DIR;
+    const BUILTIN: &'static [&'static str] = BUILTIN_ZONES;
+
+    fn from_definitions(mut definitions: Vec<ZoneDefinition>) -> Result<Self> {
+        let monsters = MonsterRegistry::global();
+
+        for (index, definition) in definitions.iter().enumerate() {
+            if definitions[..index]
+                .iter()
+                .any(|known| known.id == definition.id)
+            {
+                return Err(eyre!("Zone defined twice: {}", definition.id));
+            }
+            if let Some(required) = &definition.unlocked_by {
+                if !definitions.iter().any(|known| &known.id == required) {
+                    return Err(eyre!(
+                        "Zone {} is unlocked by unknown zone {required}",
+                        definition.id
+                    ));
+                }
+            }
+            if let Some(name) = definition
+                .unique_monsters
+                .iter()
+                .find(|name| monsters.get(name).is_none())
+            {
+                return Err(eyre!("Zone {} has unknown monster {name}", definition.id));
+            }
+            if !monsters
+                .get(&definition.boss_name)
+                .is_some_and(MonsterDefinition::is_boss)
+            {
+                return Err(eyre!(
+                    "Zone {} has unknown boss {}",
+                    definition.id,
+                    definition.boss_name
+                ));
+            }
+            if definition.levels.is_empty() {
+                return Err(eyre!("Zone {} has no difficulty", definition.name));
+            }
+            if definition.room_count < 1 {
+                return Err(eyre!("Zone {} has no rooms", definition.name));
+            }
+        }
+
+        if definitions.is_empty() {
+            return Err(eyre!("No zone definitions found"));
+        }
+
+        // The campaign goes through zones from the easiest to the hardest
+        definitions.sort_by_key(|definition| definition.recommended_level);
+
+        Ok(Self { definitions })
+    }
+}
+
+impl ZoneCatalogue {
+    /// The catalogue shared by the whole game.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        data::global(&CATALOGUE)
+    }
+
+    /// Loads the shared catalogue, reporting invalid zone files before the game starts.
+    pub fn init() -> Result<()> {
+        data::init_global(&CATALOGUE)
+    }
+
+    #[must_use]
+    pub fn definitions(&self) -> &[ZoneDefinition] {
+        &self.definitions
+    }
+}
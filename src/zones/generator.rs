@@ -1,28 +1,60 @@
-use rand::Rng;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use crate::{
-    monsters::balancer::{MonsterBalancer, RoomData},
-    room::{Room, RoomType},
+    loot::{generate_random_armor, generate_weapon, roll_rarity},
+    monsters::{
+        balancer::{MonsterBalancer, RoomData},
+        registry::MonsterRegistry,
+    },
+    room::{Room, RoomType, Treasure},
+    shop::Shop,
     zones::zone::Zone,
 };
 
+const MERCHANT_CHANCE: f64 = 0.1;
+
 #[derive(Default, Debug)]
 pub struct RoomGenerator {
     balancer: MonsterBalancer,
+    seed: u64,
 }
 
 impl RoomGenerator {
     #[must_use]
-    pub const fn new() -> Self {
+    pub const fn new(seed: u64) -> Self {
         Self {
             balancer: MonsterBalancer::new(),
+            seed,
         }
     }
 
     #[must_use]
-    pub fn generate_room(&self, zone: &Zone, room_number: i32) -> Room {
-        let room_type = determine_room_type(room_number);
-        let room_size = determine_room_size(&room_type);
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Every room of the map gets its own generator derived from the run seed, so
+    /// a room can be regenerated identically without replaying the rooms before it.
+    #[must_use]
+    pub fn room_rng(&self, room_number: i32, column: usize) -> StdRng {
+        #[allow(clippy::cast_sign_loss)]
+        let room_salt = (room_number as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let column_salt = (column as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+        StdRng::seed_from_u64(self.seed ^ room_salt ^ column_salt)
+    }
+
+    /// Type of the room at this spot of the map, as `generate_room` will roll it.
+    #[must_use]
+    pub fn room_type(&self, zone: &Zone, room_number: i32, column: usize) -> RoomType {
+        determine_room_type(room_number, zone, &mut self.room_rng(room_number, column))
+    }
+
+    #[must_use]
+    pub fn generate_room(&self, zone: &Zone, room_number: i32, column: usize) -> Room {
+        let mut rng = self.room_rng(room_number, column);
+
+        let room_type = determine_room_type(room_number, zone, &mut rng);
+        let room_size = determine_room_size(&room_type, &mut rng);
 
         let room_data = RoomData {
             zone: zone.clone(),
@@ -32,18 +64,27 @@ impl RoomGenerator {
 
         let monsters = self
             .balancer
-            .generate_monsters_for_room(&room_data, room_size);
+            .generate_monsters_for_room(&room_data, room_size, &mut rng);
 
-        Room::new(room_number, zone.clone(), room_type, monsters)
+        if room_type == RoomType::Merchant {
+            let shop = Shop::generate(&zone.difficulty, &mut rng);
+            return Room::new(room_number, zone.clone(), room_type, monsters, Vec::new())
+                .with_shop(shop);
+        }
+
+        let treasures = vec![generate_treasure(zone, &room_type, &mut rng)];
+
+        Room::new(room_number, zone.clone(), room_type, monsters, treasures)
     }
 }
 
-fn determine_room_type(room_number: i32) -> RoomType {
-    let mut rng = rand::rng();
+fn determine_room_type<R: Rng>(room_number: i32, zone: &Zone, rng: &mut R) -> RoomType {
     let roll: f64 = rng.random();
+    // Les difficultés supérieures remplacent des salles normales par des salles d'élite
+    let elite_bonus = zone.difficulty.elite_bonus();
 
-    // Boss room à la salle 10
-    if room_number == 10 {
+    // Boss room à la dernière salle de la zone
+    if room_number == zone.room_count {
         return RoomType::Boss;
     }
 
@@ -52,26 +93,57 @@ fn determine_room_type(room_number: i32) -> RoomType {
         return RoomType::Treasure;
     }
 
+    // Un marchand peut s'installer après les premières salles
+    if room_number > 2 && rng.random_bool(MERCHANT_CHANCE) {
+        return RoomType::Merchant;
+    }
+
     match room_number {
         1 => RoomType::Entrance,
-        2..=4 if roll < 0.8 => RoomType::Normal,
+        2..=4 if roll < 0.8 - elite_bonus => RoomType::Normal,
         2..=4 => RoomType::Elite,
-        5..=9 if roll < 0.7 => RoomType::Normal,
+        5..=9 if roll < 0.7 - elite_bonus => RoomType::Normal,
         5..=9 if roll < 0.9 => RoomType::Elite,
         5..=9 => RoomType::Treasure,
-        _ if roll < 0.6 => RoomType::Normal,
+        _ if roll < 0.6 - elite_bonus => RoomType::Normal,
         _ if roll < 0.85 => RoomType::Elite,
         _ => RoomType::Treasure,
     }
 }
 
-fn determine_room_size(room_type: &RoomType) -> usize {
-    let mut rng = rand::rng();
+fn determine_room_size<R: Rng>(room_type: &RoomType, rng: &mut R) -> usize {
     match room_type {
         RoomType::Entrance => rng.random_range(1..=2),
         RoomType::Normal => rng.random_range(2..=4),
         RoomType::Elite => rng.random_range(1..=3),
-        RoomType::Treasure => 0,
+        RoomType::Treasure | RoomType::Merchant => 0,
         RoomType::Boss => 1, // Le boss est seul
     }
 }
+
+fn generate_treasure<R: Rng>(zone: &Zone, room_type: &RoomType, rng: &mut R) -> Treasure {
+    let boss_reward = MonsterRegistry::global()
+        .get(&zone.boss_name)
+        .and_then(|boss| boss.reward.as_ref());
+
+    match (room_type, boss_reward) {
+        (RoomType::Boss, Some(reward)) => {
+            let rarity = roll_rarity(&zone.difficulty, room_type, rng);
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let gold = (f64::from(reward.gold) * zone.difficulty.gold_multiplier()) as u32;
+
+            Treasure::new(
+                Some(generate_weapon(
+                    reward.weapon_type.clone(),
+                    rarity.clone(),
+                    rng,
+                )),
+                Some(generate_random_armor(rarity, rng)),
+                Some(gold),
+                None,
+            )
+        }
+        _ => Treasure::generate(&zone.difficulty, room_type, rng),
+    }
+}
@@ -0,0 +1,172 @@
+use rand::{rngs::StdRng, seq::IndexedRandom, Rng, SeedableRng};
+
+use crate::{
+    room::RoomType,
+    zones::{generator::RoomGenerator, zone::Zone},
+};
+
+/// Columns a layer of the map can spread over.
+pub const MAP_WIDTH: usize = 3;
+/// Chance for a room to open a second path towards the next layer.
+const BRANCH_CHANCE: f64 = 0.5;
+const MAP_SALT: u64 = 0xA5A5_5A5A_C3C3_3C3C;
+
+/// A room of the map, known ahead of time by its type only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapNode {
+    pub column: usize,
+    pub room_type: RoomType,
+    /// Columns of the rooms of the next layer this one leads to.
+    pub next: Vec<usize>,
+}
+
+/// The rooms of a zone laid out in layers, one per room number. The player
+/// picks one of the rooms the current one leads to each time they move on,
+/// from the single entrance up to the single boss room.
+#[derive(Debug, Default, Clone)]
+pub struct DungeonMap {
+    layers: Vec<Vec<MapNode>>,
+}
+
+impl DungeonMap {
+    /// Lays out the zone's map from the run seed. Room types come from the
+    /// same per-room generators as the rooms, so they match once entered.
+    #[must_use]
+    pub fn generate(zone: &Zone, generator: &RoomGenerator) -> Self {
+        let mut rng = StdRng::seed_from_u64(generator.seed() ^ MAP_SALT);
+        let room_count = usize::try_from(zone.room_count).unwrap_or(0);
+
+        // L'entrée et la salle du boss sont seules sur leur couche, au centre
+        let mut layers: Vec<Vec<MapNode>> = (1..=room_count)
+            .map(|layer| {
+                let columns = if layer == 1 || layer == room_count {
+                    vec![MAP_WIDTH / 2]
+                } else {
+                    layer_columns(&mut rng)
+                };
+
+                columns
+                    .into_iter()
+                    .map(|column| MapNode {
+                        column,
+                        room_type: generator.room_type(zone, room_number(layer), column),
+                        next: Vec::new(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        for layer in 0..layers.len().saturating_sub(1) {
+            let next_columns: Vec<usize> =
+                layers[layer + 1].iter().map(|node| node.column).collect();
+            connect(&mut layers[layer], &next_columns, &mut rng);
+        }
+
+        Self { layers }
+    }
+
+    /// The rooms of a layer, from the room number.
+    #[must_use]
+    pub fn layer(&self, room_number: i32) -> &[MapNode] {
+        usize::try_from(room_number - 1)
+            .ok()
+            .and_then(|index| self.layers.get(index))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    #[must_use]
+    pub fn node(&self, room_number: i32, column: usize) -> Option<&MapNode> {
+        self.layer(room_number)
+            .iter()
+            .find(|node| node.column == column)
+    }
+
+    /// Column of the room the zone starts in.
+    #[must_use]
+    pub fn entrance(&self) -> usize {
+        self.layer(1)
+            .first()
+            .map_or(MAP_WIDTH / 2, |node| node.column)
+    }
+}
+
+fn room_number(layer: usize) -> i32 {
+    i32::try_from(layer).unwrap_or(i32::MAX)
+}
+
+/// Two or three of the columns, in order.
+fn layer_columns<R: Rng>(rng: &mut R) -> Vec<usize> {
+    if rng.random_bool(0.5) {
+        return (0..MAP_WIDTH).collect();
+    }
+
+    let skipped = rng.random_range(0..MAP_WIDTH);
+    (0..MAP_WIDTH).filter(|column| *column != skipped).collect()
+}
+
+/// Links every room of a layer to the next one. Paths only go to the same or a
+/// neighbouring column and never cross, and every room of the next layer can be reached.
+fn connect<R: Rng>(layer: &mut [MapNode], next_columns: &[usize], rng: &mut R) {
+    // Chaque salle mène au moins à la salle la plus proche
+    for node in layer.iter_mut() {
+        if let Some(&closest) = next_columns
+            .iter()
+            .min_by_key(|column| column.abs_diff(node.column))
+        {
+            node.next.push(closest);
+        }
+    }
+
+    // Parfois un second chemin vers une colonne voisine
+    for index in 0..layer.len() {
+        let column = layer[index].column;
+        let candidates: Vec<usize> = next_columns
+            .iter()
+            .copied()
+            .filter(|next| next.abs_diff(column) <= 1 && !layer[index].next.contains(next))
+            .filter(|next| !crosses(layer, column, *next))
+            .collect();
+
+        if rng.random_bool(BRANCH_CHANCE) {
+            if let Some(&next) = candidates.choose(rng) {
+                layer[index].next.push(next);
+            }
+        }
+    }
+
+    // Aucune salle de la couche suivante ne doit rester inaccessible
+    for &next in next_columns {
+        if layer.iter().any(|node| node.next.contains(&next)) {
+            continue;
+        }
+        let source = layer
+            .iter()
+            .filter(|node| node.column.abs_diff(next) <= 1)
+            .min_by_key(|node| {
+                (
+                    crosses(layer, node.column, next),
+                    node.column.abs_diff(next),
+                )
+            })
+            .map(|node| node.column);
+
+        if let Some(node) =
+            source.and_then(|column| layer.iter_mut().find(|node| node.column == column))
+        {
+            node.next.push(next);
+        }
+    }
+
+    for node in layer.iter_mut() {
+        node.next.sort_unstable();
+    }
+}
+
+/// Whether a path from `column` to `next` would cross one already laid.
+fn crosses(layer: &[MapNode], column: usize, next: usize) -> bool {
+    layer.iter().any(|node| {
+        node.next.iter().any(|&other| {
+            (node.column < column && other > next) || (node.column > column && other < next)
+        })
+    })
+}
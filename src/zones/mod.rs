@@ -1,2 +1,4 @@
+pub mod catalogue;
 pub mod generator;
-pub mod zone;
\ No newline at end of file
+pub mod map;
+pub mod zone;
@@ -1,87 +1,115 @@
 use std::fmt::Display;
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+use crate::zones::catalogue::{ZoneCatalogue, ZoneDefinition, ZoneLevels};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ZoneDifficulty {
     #[default]
     Normal,
+    Hard,
+    Nightmare,
 }
 
 impl Display for ZoneDifficulty {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            &Self::Normal => write!(f, "Normal"),
+            Self::Normal => write!(f, "Normal"),
+            Self::Hard => write!(f, "Hard"),
+            Self::Nightmare => write!(f, "Nightmare"),
         }
     }
 }
 
-#[derive(Default, Debug, Clone)]
-pub enum ZoneType {
-    #[default]
-    Jungle,
-}
+impl ZoneDifficulty {
+    #[must_use]
+    pub const fn harder(&self) -> Self {
+        match self {
+            Self::Normal => Self::Hard,
+            Self::Hard | Self::Nightmare => Self::Nightmare,
+        }
+    }
 
-impl Display for ZoneType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    #[must_use]
+    pub const fn easier(&self) -> Self {
         match self {
-            &Self::Jungle => write!(f, "Jungle"),
+            Self::Normal | Self::Hard => Self::Normal,
+            Self::Nightmare => Self::Hard,
+        }
+    }
+
+    /// Share of Normal rooms turned into Elite rooms.
+    #[must_use]
+    pub const fn elite_bonus(&self) -> f64 {
+        match self {
+            Self::Normal => 0.0,
+            Self::Hard => 0.15,
+            Self::Nightmare => 0.3,
+        }
+    }
+
+    #[must_use]
+    pub const fn gold_multiplier(&self) -> f64 {
+        match self {
+            Self::Normal => 1.0,
+            Self::Hard => 1.5,
+            Self::Nightmare => 2.5,
         }
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Zone {
-    pub zone_type: ZoneType,
+    /// Identifier of the zone in the catalogue.
+    #[serde(alias = "zone_type")]
+    pub id: String,
     pub difficulty: ZoneDifficulty,
     pub name: String,
     pub description: String,
     pub recommended_level: i32,
     pub unique_monsters: Vec<String>,
     pub boss_name: String,
+    pub level_range: (i32, i32),
+    pub room_count: i32,
+    pub unlocked_by: Option<String>,
 }
 
 impl Zone {
     #[must_use]
-    pub fn new(zone_type: ZoneType, difficulty: ZoneDifficulty) -> Self {
-        let (name, description, recommended_level, unique_monsters, boss_name) = match zone_type {
-            ZoneType::Jungle => Self::jungle_data(&difficulty),
-        };
-
+    pub fn new(definition: &ZoneDefinition, levels: &ZoneLevels) -> Self {
         Self {
-            zone_type,
-            difficulty,
-            name,
-            description,
-            recommended_level,
-            unique_monsters,
-            boss_name,
-        }
-    }
-
-    fn jungle_data(difficulty: &ZoneDifficulty) -> (String, String, i32, Vec<String>, String) {
-        match difficulty {
-            ZoneDifficulty::Normal => (
-                "Jungle".to_string(),
-                "Dangerous heart of the jungle".to_string(),
-                1,
-                vec![
-                    "Slime".to_string(),
-                    "Goblin".to_string(),
-                    "Ogre".to_string(),
-                ],
-                "Giant Spider".to_string(),
-            ),
+            id: definition.id.clone(),
+            difficulty: levels.difficulty.clone(),
+            name: definition.name.clone(),
+            description: definition.description.clone(),
+            recommended_level: definition.recommended_level,
+            unique_monsters: definition.unique_monsters.clone(),
+            boss_name: definition.boss_name.clone(),
+            level_range: (levels.min_level, levels.max_level),
+            room_count: definition.room_count,
+            unlocked_by: definition.unlocked_by.clone(),
         }
     }
 
     #[must_use]
     pub const fn get_monster_level_range(&self) -> (i32, i32) {
-        match self.difficulty {
-            ZoneDifficulty::Normal => (1, 4),
-        }
+        self.level_range
     }
 
+    /// Every catalogued biome that can be played at the given difficulty.
     #[must_use]
-    pub fn get_available_zones() -> Vec<Self> {
-        vec![Self::new(ZoneType::Jungle, ZoneDifficulty::Normal)]
+    pub fn get_available_zones(difficulty: &ZoneDifficulty) -> Vec<Self> {
+        ZoneCatalogue::global()
+            .definitions()
+            .iter()
+            .filter_map(|definition| {
+                definition
+                    .levels
+                    .iter()
+                    .find(|levels| &levels.difficulty == difficulty)
+                    .map(|levels| Self::new(definition, levels))
+            })
+            .collect()
     }
 }
@@ -0,0 +1,146 @@
+use ardentia::{
+    class::CharacterClass,
+    entity::Player,
+    game::{GameCommand, GameEvent, GameState, Phase},
+    inventory::Consumable,
+    room::{HealthPotion, WeaponUtils},
+    zones::zone::{Zone, ZoneDifficulty},
+};
+
+const SEED: u64 = 3;
+
+fn new_run() -> GameState {
+    let zone = Zone::get_available_zones(&ZoneDifficulty::Normal)[0].clone();
+    let mut player = Player::with_class("Tester", CharacterClass::Warrior);
+    // The test is about the flow of commands, not about surviving the fight
+    player.max_health = 1000;
+    player.health = 1000;
+
+    GameState::new_run(player, zone, SEED)
+}
+
+#[test]
+fn same_seed_generates_the_same_room() {
+    let names = |game: &GameState| -> Vec<String> {
+        game.dungeon
+            .get_current_room_immutable()
+            .monsters
+            .iter()
+            .map(|monster| monster.get_stats().name)
+            .collect()
+    };
+
+    assert_eq!(names(&new_run()), names(&new_run()));
+}
+
+#[test]
+fn same_seed_plays_the_same_fight() {
+    let play = || {
+        let mut game = new_run();
+        let mut events = game.apply(GameCommand::EnterRoom);
+        loop {
+            let target = game
+                .dungeon
+                .get_current_room_immutable()
+                .living_monsters()
+                .next();
+            let Some(target) = target else {
+                break;
+            };
+            events.extend(game.apply(GameCommand::Attack(target)));
+        }
+        format!("{events:?}")
+    };
+
+    assert_eq!(play(), play());
+}
+
+#[test]
+fn entering_a_room_reveals_its_monsters() {
+    let mut game = new_run();
+    let monster_count = game.dungeon.get_current_room_immutable().monsters.len();
+
+    let events = game.apply(GameCommand::EnterRoom);
+
+    assert_eq!(events.len(), monster_count);
+    assert!(events
+        .iter()
+        .all(|event| matches!(event, GameEvent::MonsterAppeared { .. })));
+}
+
+#[test]
+fn clearing_a_room_and_equipping_its_weapon() {
+    let mut game = new_run();
+    let monster_count = game.dungeon.get_current_room_immutable().monsters.len();
+    game.apply(GameCommand::EnterRoom);
+
+    let mut slain = 0;
+    for _ in 0..100 {
+        let Some(target) = game
+            .dungeon
+            .get_current_room_immutable()
+            .living_monsters()
+            .next()
+        else {
+            break;
+        };
+
+        for event in game.apply(GameCommand::Attack(target)) {
+            match event {
+                GameEvent::MonsterSlain { .. } => slain += 1,
+                GameEvent::PlayerDied => panic!("the player should outlast the entrance"),
+                _ => (),
+            }
+        }
+    }
+    assert_eq!(slain, monster_count);
+    assert!(game.dungeon.get_current_room_immutable().is_empty());
+
+    let treasures = &game.dungeon.get_current_room_immutable().treasures;
+    assert!(treasures.contains_weapon());
+    let weapon = treasures.get_weapon().expect("the entrance holds a weapon");
+
+    let events = game.apply(GameCommand::EquipWeapon);
+
+    assert!(events.iter().any(
+        |event| matches!(event, GameEvent::WeaponEquipped(equipped) if equipped.name == weapon.name)
+    ));
+    assert!(matches!(events.last(), Some(GameEvent::MapOpened)));
+    assert_eq!(
+        game.player
+            .weapon
+            .as_ref()
+            .map(|weapon| weapon.name.clone()),
+        Some(weapon.name)
+    );
+}
+
+#[test]
+fn commands_out_of_place_do_nothing() {
+    let mut game = new_run();
+    game.player
+        .inventory
+        .add(Consumable::HealthPotion(HealthPotion::new(5)));
+
+    // Nothing can happen in a room before the player steps into it
+    for command in [
+        GameCommand::EquipWeapon,
+        GameCommand::KeepWeapon,
+        GameCommand::Run,
+        GameCommand::UseItem(0),
+        GameCommand::Attack(0),
+        GameCommand::ChooseRoom(1),
+        GameCommand::LeaveShop,
+    ] {
+        assert!(game.apply(command).is_empty());
+    }
+    assert_eq!(game.phase, Phase::Exploring);
+    assert_eq!(game.player.inventory.len(), 1);
+    assert!(!game.dungeon.get_current_room_immutable().is_empty());
+
+    game.apply(GameCommand::EnterRoom);
+    assert_eq!(game.phase, Phase::Fighting);
+    // The treasure waits until the room is cleared
+    assert!(game.apply(GameCommand::EquipWeapon).is_empty());
+    assert!(game.apply(GameCommand::EnterRoom).is_empty());
+}
@@ -0,0 +1,99 @@
+use ardentia::zones::{
+    generator::RoomGenerator,
+    map::{DungeonMap, MAP_WIDTH},
+    zone::{Zone, ZoneDifficulty},
+};
+
+/// Maps laid out for every zone from these seeds are checked one by one.
+const SEEDS: u64 = 50;
+
+fn maps() -> impl Iterator<Item = (Zone, u64, DungeonMap)> {
+    [
+        ZoneDifficulty::Normal,
+        ZoneDifficulty::Hard,
+        ZoneDifficulty::Nightmare,
+    ]
+    .iter()
+    .flat_map(Zone::get_available_zones)
+    .flat_map(|zone| {
+        (0..SEEDS).map(move |seed| {
+            let map = DungeonMap::generate(&zone, &RoomGenerator::new(seed));
+            (zone.clone(), seed, map)
+        })
+    })
+}
+
+#[test]
+fn entrance_and_boss_rooms_stand_alone() {
+    for (zone, seed, map) in maps() {
+        assert_eq!(map.layer(1).len(), 1, "{} seed {seed}", zone.id);
+        assert_eq!(
+            map.layer(zone.room_count).len(),
+            1,
+            "{} seed {seed}",
+            zone.id
+        );
+    }
+}
+
+#[test]
+fn every_room_leads_to_a_neighbouring_room() {
+    for (zone, seed, map) in maps() {
+        for room_number in 1..zone.room_count {
+            for node in map.layer(room_number) {
+                let context = format!("{} seed {seed} room {room_number}", zone.id);
+
+                assert!(!node.next.is_empty(), "dead end, {context}");
+                for &next in &node.next {
+                    assert!(next < MAP_WIDTH, "{context}");
+                    assert!(next.abs_diff(node.column) <= 1, "{context}");
+                    assert!(map.node(room_number + 1, next).is_some(), "{context}");
+                }
+            }
+        }
+        for node in map.layer(zone.room_count) {
+            assert!(node.next.is_empty(), "{} seed {seed}", zone.id);
+        }
+    }
+}
+
+#[test]
+fn every_room_can_be_reached() {
+    for (zone, seed, map) in maps() {
+        for room_number in 2..=zone.room_count {
+            for node in map.layer(room_number) {
+                assert!(
+                    map.layer(room_number - 1)
+                        .iter()
+                        .any(|previous| previous.next.contains(&node.column)),
+                    "{} seed {seed} room {room_number} column {}",
+                    zone.id,
+                    node.column
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn paths_never_cross() {
+    for (zone, seed, map) in maps() {
+        for room_number in 1..zone.room_count {
+            let paths: Vec<(usize, usize)> = map
+                .layer(room_number)
+                .iter()
+                .flat_map(|node| node.next.iter().map(|&next| (node.column, next)))
+                .collect();
+
+            for &(from, to) in &paths {
+                for &(other_from, other_to) in &paths {
+                    assert!(
+                        !(from < other_from && to > other_to),
+                        "{} seed {seed} room {room_number}: {from}->{to} crosses {other_from}->{other_to}",
+                        zone.id
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::{env, fs, path::PathBuf, process};
+
+use ardentia::{
+    app::App,
+    class::CharacterClass,
+    entity::Player,
+    game::{CombatRng, GameCommand, GameState, Phase},
+    monsters::create_monster,
+    screen::Screen,
+    status::{StatusEffect, StatusKind},
+    zones::zone::{Zone, ZoneDifficulty},
+};
+use rand::RngCore;
+use serde_json::Value;
+
+const SEED: u64 = 3;
+
+/// A save file of its own for each test, so that they can run side by side.
+fn save_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("ardentia_{name}_{}.json", process::id()))
+}
+
+/// A run in the middle of its first fight, with a wounded boss joining in and
+/// a poisoned monster, so that every part of the save has something to hold.
+fn app_in_a_fight() -> App {
+    let zone = Zone::get_available_zones(&ZoneDifficulty::Normal)[0].clone();
+    let mut app = App {
+        game: GameState::new_run(
+            Player::with_class("Tester", CharacterClass::Warrior),
+            zone,
+            SEED,
+        ),
+        ..App::default()
+    };
+    app.game.apply(GameCommand::EnterRoom);
+    app.switch_screen(Screen::Combat);
+
+    let mut boss = create_monster(&app.game.dungeon.current_zone.boss_name, 3).unwrap();
+    boss.take_damage(boss.get_stats().health * 7 / 10);
+    let _ = boss.attack();
+    boss.statuses_mut().apply(StatusEffect {
+        kind: StatusKind::Weakness,
+        potency: 2,
+        turns: 3,
+    });
+
+    let room = app.game.dungeon.get_current_room_mutable();
+    room.monsters[0].statuses_mut().apply(StatusEffect {
+        kind: StatusKind::Poison,
+        potency: 3,
+        turns: 2,
+    });
+    room.monsters.push(boss);
+
+    app.game.combat_rng.next_u64();
+    app
+}
+
+fn monsters(app: &App) -> Vec<(Value, Option<String>, Option<String>)> {
+    app.game
+        .dungeon
+        .get_current_room_immutable()
+        .monsters
+        .iter()
+        .map(|monster| {
+            (
+                serde_json::to_value(monster.save()).unwrap(),
+                monster.phase_name(),
+                monster.next_special_attack(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn saved_run_loads_as_it_was() {
+    let path = save_path("round_trip");
+    let mut app = app_in_a_fight();
+    app.save_game(&path).unwrap();
+
+    let mut loaded = App::default();
+    loaded.load_game(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&loaded.game.player).unwrap(),
+        serde_json::to_value(&app.game.player).unwrap()
+    );
+    // The boss is last, well into one of its phases
+    assert!(monsters(&app).last().unwrap().1.is_some());
+    assert_eq!(monsters(&loaded), monsters(&app));
+    assert_eq!(loaded.game.phase, Phase::Fighting);
+    assert_eq!(loaded.current_screen, Screen::Combat);
+    assert_eq!(
+        loaded.game.combat_rng.next_u64(),
+        app.game.combat_rng.next_u64()
+    );
+}
+
+#[test]
+fn older_saves_still_load() {
+    let path = save_path("older");
+    let app = app_in_a_fight();
+    app.save_game(&path).unwrap();
+
+    // Take the save back to what older versions wrote
+    let mut save: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    for (pointer, field) in [
+        ("", "combat_rng"),
+        ("", "phase"),
+        ("/dungeon", "current_column"),
+        ("/dungeon/current_room", "last_slain"),
+    ] {
+        let object = save.pointer_mut(pointer).unwrap().as_object_mut().unwrap();
+        object.remove(field).unwrap();
+    }
+    for pointer in ["/dungeon/current_zone", "/dungeon/current_room/zone"] {
+        let zone = save.pointer_mut(pointer).unwrap().as_object_mut().unwrap();
+        let id = zone.remove("id").unwrap();
+        zone.insert("zone_type".to_string(), id);
+    }
+    fs::write(&path, save.to_string()).unwrap();
+
+    let mut loaded = App::default();
+    loaded.load_game(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let dungeon = &loaded.game.dungeon;
+    assert_eq!(dungeon.current_zone.id, app.game.dungeon.current_zone.id);
+    assert_eq!(dungeon.current_column, app.game.dungeon.current_column);
+    assert_eq!(dungeon.get_current_room_immutable().last_slain, None);
+    // Without a phase, the screen the run was saved on tells where it stood
+    assert_eq!(loaded.game.phase, Phase::Fighting);
+    assert_eq!(
+        loaded.game.combat_rng.next_u64(),
+        CombatRng::new(SEED).next_u64()
+    );
+}
+
+#[test]
+fn loaded_run_plays_on_the_same() {
+    let path = save_path("play_on");
+    let zone = Zone::get_available_zones(&ZoneDifficulty::Normal)[0].clone();
+    let mut player = Player::with_class("Tester", CharacterClass::Warrior);
+    player.max_health = 1000;
+    player.health = 1000;
+    let mut app = App {
+        game: GameState::new_run(player, zone, SEED),
+        ..App::default()
+    };
+    app.game.apply(GameCommand::EnterRoom);
+    app.game.apply(GameCommand::Attack(0));
+    app.switch_screen(Screen::Combat);
+    app.save_game(&path).unwrap();
+
+    let mut loaded = App::default();
+    loaded.load_game(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    // Both copies answer the same commands with the same events, turn after turn
+    loop {
+        let target = app
+            .game
+            .dungeon
+            .get_current_room_immutable()
+            .living_monsters()
+            .next();
+        let Some(target) = target else {
+            break;
+        };
+        assert_eq!(loaded.game.upcoming_turns(5), app.game.upcoming_turns(5));
+        let events = app.game.apply(GameCommand::Attack(target));
+        let loaded_events = loaded.game.apply(GameCommand::Attack(target));
+        assert_eq!(format!("{loaded_events:?}"), format!("{events:?}"));
+    }
+}